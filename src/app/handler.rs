@@ -1,17 +1,42 @@
-use crate::app::AppState;
-use crate::model::Transaction;
+use crate::app::{AppState, ConnectionState};
+use crate::model::{chains, Transaction};
+use crate::rpc::{ActiveEndpoint, ChainEvent, MinedBlock};
+use alloy::rpc::types::TransactionReceipt;
 use anyhow::Result;
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 
 pub enum AppEvent {
     Input(KeyEvent),
     Transaction(Transaction),
-    Connected,
+    Connection(ConnectionState),
+    Endpoint(ActiveEndpoint), // Connection task switched endpoints
+    ChainDetected(u64), // Chain ID reported by the connected endpoint
+    BlockMined(MinedBlock), // New block seen by the lifecycle tracker
+    ReceiptsFetched(Vec<TransactionReceipt>), // Receipts of tracked transactions
+    DropChecked {
+        hashes: Vec<String>, // Timed-out pending transactions that were looked up
+        receipts: Option<Vec<TransactionReceipt>>, // None if the lookup failed
+    },
+    Finalized(u64), // Latest finalized block number
+    RaceEndpoint {
+        index: usize, // Position in `RPC_URLS`
+        connected: bool,
+        error: Option<String>,
+    },
     Disconnected(String),
     TransactionFetched(Transaction), // Fetched transaction result
     TransactionNotFound(String), // Hash that wasn't found
 }
 
+impl From<ChainEvent> for AppEvent {
+    fn from(event: ChainEvent) -> Self {
+        match event {
+            ChainEvent::BlockMined(block) => Self::BlockMined(block),
+            ChainEvent::Finalized(number) => Self::Finalized(number),
+        }
+    }
+}
+
 impl AppEvent {
     /// Process the event and update application state
     pub async fn process(self, state: &mut AppState) -> Result<()> {
@@ -21,8 +46,36 @@ impl AppEvent {
                 state.add_transaction(tx);
                 Ok(())
             }
-            Self::Connected => {
-                state.set_connected(true);
+            Self::Connection(connection) => {
+                state.set_connection_state(connection);
+                Ok(())
+            }
+            Self::Endpoint(endpoint) => {
+                state.stats.active_endpoint = Some(endpoint);
+                Ok(())
+            }
+            Self::ChainDetected(chain_id) => {
+                state.stats.chain = Some(chains::chains().lookup(chain_id));
+                Ok(())
+            }
+            Self::BlockMined(block) => {
+                state.apply_mined_block(block);
+                Ok(())
+            }
+            Self::ReceiptsFetched(receipts) => {
+                state.apply_receipts(receipts);
+                Ok(())
+            }
+            Self::DropChecked { hashes, receipts } => {
+                state.apply_drop_checks(hashes, receipts);
+                Ok(())
+            }
+            Self::Finalized(number) => {
+                state.stats.finalized_block = Some(number);
+                Ok(())
+            }
+            Self::RaceEndpoint { index, connected, error } => {
+                state.set_race_endpoint(index, connected, error);
                 Ok(())
             }
             Self::Disconnected(error) => {
@@ -86,6 +139,16 @@ fn handle_details_navigation(key: KeyEvent, state: &mut AppState) -> Result<()>
         // Jump to top
         Home | Char('g') => state.details_scroll_offset = 0,
 
+        // Amount display
+        Char('u') => state.cycle_value_unit(),
+        Char('p') => state.toggle_full_precision(),
+
+        // Raw JSON-RPC object
+        Char('R') => state.toggle_raw_json(),
+
+        // Jump between a transaction and its replacement
+        Char('l') => state.follow_replacement_link(),
+
         _ => {}
     }
     Ok(())
@@ -120,6 +183,9 @@ fn handle_filter_input(key: KeyEvent, state: &mut AppState) -> Result<()> {
             }
         }
 
+        // Switch between query language and regex
+        Char('r') if key.modifiers.contains(KeyModifiers::CONTROL) => state.filter.toggle_mode(),
+
         // Character input
         Char(c) => state.filter.add_char(c),
 
@@ -187,8 +253,10 @@ fn handle_main_navigation(key: KeyEvent, state: &mut AppState) -> Result<()> {
         End | Char('G') => state.jump_to_bottom(),
 
         // Actions
-        Char('r') => state.set_connected(false), // Trigger reconnect
+        Char('r') => state.request_reconnect(),
         Char('t') => state.toggle_sort_order(),
+        Char('u') => state.cycle_value_unit(),
+        Char('p') => state.toggle_full_precision(),
         Char('c') => state.clear_transactions(),
         Char('C') if key.modifiers.contains(KeyModifiers::SHIFT) => state.clear_transactions(),
         Enter => state.show_transaction_details(),
@@ -1,5 +1,6 @@
 pub mod handler;
+pub mod race;
 pub mod state;
 
 pub use handler::{handle_event, AppEvent};
-pub use state::{AppState, Config, Stats};
\ No newline at end of file
+pub use state::{AppState, Config, ConnectionState, Stats};
\ No newline at end of file
@@ -0,0 +1,111 @@
+use crate::model::Transaction;
+
+/// Connection status of one endpoint in race mode
+#[derive(Debug, Clone)]
+pub struct RaceEndpoint {
+    pub name: String, // Display label only; endpoints are keyed by their index in `RPC_URLS`
+    pub connected: bool,
+}
+
+/// Propagation figures for one endpoint over the buffered transactions
+#[derive(Debug, Clone, PartialEq)]
+pub struct RaceSummary {
+    pub name: String,
+    pub connected: bool,
+    pub seen: usize,
+    pub first: usize,
+    pub first_pct: f64,
+    pub avg_lag_ms: Option<f64>, // Average delay behind the first endpoint when not first
+}
+
+/// Summarise which endpoints delivered transactions first, and how far behind the others were
+pub fn summarize<'a>(
+    endpoints: &[RaceEndpoint],
+    transactions: impl IntoIterator<Item = &'a Transaction>,
+) -> Vec<RaceSummary> {
+    let mut summaries: Vec<RaceSummary> = endpoints
+        .iter()
+        .map(|endpoint| RaceSummary {
+            name: endpoint.name.clone(),
+            connected: endpoint.connected,
+            seen: 0,
+            first: 0,
+            first_pct: 0.0,
+            avg_lag_ms: None,
+        })
+        .collect();
+    let mut lag_totals = vec![(0i64, 0usize); summaries.len()];
+    let mut raced = 0;
+
+    for tx in transactions {
+        if tx.seen_by.is_empty() {
+            continue;
+        }
+        raced += 1;
+
+        for (i, sighting) in tx.seen_by.iter().enumerate() {
+            let index = sighting.endpoint;
+            let Some(summary) = summaries.get_mut(index) else {
+                continue;
+            };
+            summary.seen += 1;
+            if i == 0 {
+                summary.first += 1;
+            } else {
+                lag_totals[index].0 += tx.arrival_delta_ms(sighting);
+                lag_totals[index].1 += 1;
+            }
+        }
+    }
+
+    for (summary, (total, count)) in summaries.iter_mut().zip(lag_totals) {
+        if raced > 0 {
+            summary.first_pct = summary.first as f64 * 100.0 / raced as f64;
+        }
+        if count > 0 {
+            summary.avg_lag_ms = Some(total as f64 / count as f64);
+        }
+    }
+
+    summaries
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::Sighting;
+
+    fn raced_tx(hash: &str, sightings: &[(usize, i64)]) -> Transaction {
+        let mut tx = Transaction {
+            hash: hash.to_string(),
+            from: "0x1".to_string(),
+            gas_limit: 21000,
+            data: "0x".to_string(),
+            ..Default::default()
+        };
+        for (endpoint, received_ms) in sightings {
+            tx.add_sighting(Sighting { endpoint: *endpoint, received_ms: *received_ms });
+        }
+        tx
+    }
+
+    #[test]
+    fn test_summarize_first_seen_and_lag() {
+        let endpoints = ["a", "b"].map(|name| RaceEndpoint { name: name.to_string(), connected: true });
+        let transactions = [
+            raced_tx("0x1", &[(1, 1_030), (0, 1_000)]), // Arrival order doesn't matter
+            raced_tx("0x2", &[(0, 2_000), (1, 2_010)]),
+            raced_tx("0x3", &[(1, 3_000)]),
+        ];
+
+        let summary = summarize(&endpoints, &transactions);
+        assert_eq!(summary[0].seen, 2);
+        assert_eq!(summary[0].first, 2);
+        assert!((summary[0].first_pct - 66.6).abs() < 0.1);
+        assert_eq!(summary[0].avg_lag_ms, None);
+
+        assert_eq!(summary[1].seen, 3);
+        assert_eq!(summary[1].first, 1);
+        assert_eq!(summary[1].avg_lag_ms, Some(20.0));
+    }
+}
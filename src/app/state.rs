@@ -1,10 +1,26 @@
+use crate::app::race::RaceEndpoint;
+use alloy::primitives::U256;
 use crate::filter::FilterState;
-use crate::model::Transaction;
-use std::collections::VecDeque;
+use crate::model::registry::AbiLoadReport;
+use crate::model::chains;
+use crate::model::units::{format_amount, AmountFormat, Denomination};
+use crate::model::{ChainInfo, Transaction};
+use crate::rpc::{
+    enhance_with_receipt, ActiveEndpoint, FetchOptions, FetchSnapshot, MinedBlock, PendingTxMode, SourceMode,
+    DEFAULT_POLL_INTERVAL, MIN_POLL_INTERVAL,
+};
+use alloy::rpc::types::TransactionReceipt;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::path::PathBuf;
 use std::time::Instant;
 
 const DEFAULT_MAX_TRANSACTIONS: usize = 1000;
+const DEFAULT_FETCH_CONCURRENCY: usize = 16;
+const DEFAULT_FETCH_QUEUE_SIZE: usize = 2000;
+const DEFAULT_STALL_TIMEOUT_SECS: u64 = 60;
+const DEFAULT_DROP_TIMEOUT_SECS: u64 = 600;
 const VECDEQUE_SHRINK_THRESHOLD: usize = 2000; // Shrink if capacity exceeds this
+const MINED_NONCES_CAPACITY: usize = 10_000; // Mined (from, nonce) keys kept to spot late replacements
 
 pub struct AppState {
     pub transactions: VecDeque<Transaction>,
@@ -14,12 +30,23 @@ pub struct AppState {
     pub config: Config,
     pub should_quit: bool,
     pub show_new_on_top: bool,
+    pub value_unit: Denomination, // Unit of the value column and details value
+    pub amount_format: AmountFormat,
     pub show_details: bool,
     pub selected_transaction: Option<Transaction>,
     pub details_scroll_offset: usize,
+    pub show_raw_json: bool, // Details popup shows the JSON-RPC object instead of decoded fields
+    pub selected_raw_json: Option<String>, // Selected transaction's JSON-RPC object, pretty-printed on first view
     pub filter: FilterState,
     pub quit_confirmation: bool,
     pub pending_tx_fetch: Option<String>, // Transaction hash to fetch
+    pub pending_reconnect: bool, // Forced reconnect requested by the user
+    pub pending_receipts: Vec<(u64, Vec<String>)>, // Newly mined buffered transactions awaiting receipts, by block
+    pub pending_drop_checks: Vec<String>, // Timed-out pending transactions to look up before marking dropped
+    drop_checks_in_flight: HashSet<String>,
+    pending_by_sender: HashMap<(String, u64), String>, // Latest pending hash per (from, nonce)
+    mined_nonces: HashMap<(String, u64), String>, // Mined hash per (from, nonce)
+    mined_nonce_order: VecDeque<(String, u64)>, // Oldest first, to bound `mined_nonces`
 }
 
 pub struct ScrollState {
@@ -35,26 +62,85 @@ pub struct Stats {
     pub transactions_per_second: f32,
     pub memory_usage_mb: f32,
     pub last_perf_update: Instant,
+    pub abi_report: Option<AbiLoadReport>, // Set when any user signature source was loaded
+    pub fetch: FetchSnapshot,
+    pub latest_block: Option<u64>, // Highest block number seen on a transaction or by the tracker
+    pub finalized_block: Option<u64>, // Latest finalized block, if the node reports one
+    pub connection: ConnectionState,
+    pub active_endpoint: Option<ActiveEndpoint>,
+    pub race_endpoints: Vec<RaceEndpoint>, // Empty unless race mode is on
+    pub chain: Option<ChainInfo>, // Set once the endpoint reports its chain ID
+}
+
+impl Stats {
+    /// The connected chain, or ETH-like defaults until it is known
+    pub fn chain(&self) -> &ChainInfo {
+        self.chain.as_ref().unwrap_or_else(|| chains::fallback())
+    }
+}
+
+/// Lifecycle of the RPC connection as reported by the connection task
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConnectionState {
+    Connecting,
+    Connected,
+    /// Waiting before the next attempt; `max_attempts` of 0 means unlimited
+    Reconnecting {
+        attempt: u32,
+        max_attempts: u32,
+        retry_at: Instant,
+    },
+    /// Connected but nothing has arrived for a while
+    Stalled { since: Instant },
+    /// Out of attempts until the user asks for a retry
+    GaveUp { attempts: u32 },
 }
 
 #[derive(Clone)]
 pub struct Config {
-    pub rpc_url: String,
+    pub rpc_url: String, // Primary endpoint
+    pub rpc_urls: Vec<String>, // Primary followed by fallbacks
     pub reconnect_attempts: u32,
     pub reconnect_delay: u64,
     pub max_transactions: usize,
+    pub abi_dir: Option<PathBuf>,
+    pub signature_db: Option<PathBuf>,
+    pub contracts_file: Option<PathBuf>,
+    pub chains_file: Option<PathBuf>, // Extra or overriding chain registry entries
+    pub fetch_concurrency: usize,
+    pub fetch_queue_size: usize,
+    pub pending_tx_mode: PendingTxMode,
+    pub source_mode: SourceMode,
+    pub poll_interval_ms: u64,
+    pub race_mode: bool, // Subscribe to every endpoint at once instead of failing over
+    pub stall_timeout_secs: u64, // Reconnect after this long without transactions; 0 disables
+    pub drop_timeout_secs: u64, // Mark pending transactions dropped after this long; 0 disables
 }
 
 impl Default for Config {
     fn default() -> Self {
+        let rpc_url = std::env::var("RPC_URL")
+            .or_else(|_| std::env::var("BASE_RPC_URL")) // Backward compatibility
+            .unwrap_or_else(|_| "wss://base-rpc.publicnode.com".to_string());
         Self {
-            rpc_url: std::env::var("RPC_URL")
-                .or_else(|_| std::env::var("BASE_RPC_URL")) // Backward compatibility
-                .unwrap_or_else(|_| "wss://base-rpc.publicnode.com".to_string()),
+            rpc_urls: vec![rpc_url.clone()],
+            rpc_url,
             // wss://ethereum-sepolia-rpc.publicnode.com
             reconnect_attempts: 10,
             reconnect_delay: 5000,
             max_transactions: DEFAULT_MAX_TRANSACTIONS,
+            abi_dir: None,
+            signature_db: None,
+            contracts_file: None,
+            chains_file: None,
+            fetch_concurrency: DEFAULT_FETCH_CONCURRENCY,
+            fetch_queue_size: DEFAULT_FETCH_QUEUE_SIZE,
+            pending_tx_mode: PendingTxMode::Auto,
+            source_mode: SourceMode::Mempool,
+            poll_interval_ms: DEFAULT_POLL_INTERVAL.as_millis() as u64,
+            race_mode: false,
+            stall_timeout_secs: DEFAULT_STALL_TIMEOUT_SECS,
+            drop_timeout_secs: DEFAULT_DROP_TIMEOUT_SECS,
         }
     }
 }
@@ -64,6 +150,23 @@ impl Config {
         let mut config = Self::default();
 
         // Override from environment variables if present
+        if let Ok(urls) = std::env::var("RPC_URLS") {
+            let mut urls: Vec<String> = urls
+                .split(',')
+                .map(|url| url.trim().to_string())
+                .filter(|url| !url.is_empty())
+                .collect();
+            // An explicit RPC_URL stays the primary
+            if std::env::var("RPC_URL").is_ok() {
+                urls.retain(|url| url != &config.rpc_url);
+                urls.insert(0, config.rpc_url.clone());
+            }
+            if let Some(primary) = urls.first() {
+                config.rpc_url = primary.clone();
+                config.rpc_urls = urls;
+            }
+        }
+
         if let Ok(max_tx) = std::env::var("MAX_TRANSACTIONS") {
             config.max_transactions = max_tx.parse().unwrap_or(DEFAULT_MAX_TRANSACTIONS);
         }
@@ -76,13 +179,74 @@ impl Config {
             config.reconnect_delay = delay.parse().unwrap_or(5000);
         }
 
+        if let Ok(dir) = std::env::var("ABI_DIR") {
+            config.abi_dir = Some(PathBuf::from(dir));
+        }
+
+        if let Ok(path) = std::env::var("SIGNATURE_DB") {
+            config.signature_db = Some(PathBuf::from(path));
+        }
+
+        if let Ok(path) = std::env::var("CONTRACTS_FILE") {
+            config.contracts_file = Some(PathBuf::from(path));
+        }
+
+        if let Ok(path) = std::env::var("CHAINS_FILE") {
+            config.chains_file = Some(PathBuf::from(path));
+        }
+
+        if let Ok(concurrency) = std::env::var("FETCH_CONCURRENCY") {
+            config.fetch_concurrency = concurrency.parse().unwrap_or(DEFAULT_FETCH_CONCURRENCY);
+        }
+
+        if let Ok(size) = std::env::var("FETCH_QUEUE_SIZE") {
+            config.fetch_queue_size = size.parse().unwrap_or(DEFAULT_FETCH_QUEUE_SIZE);
+        }
+
+        if let Ok(mode) = std::env::var("PENDING_TX_MODE") {
+            config.pending_tx_mode = PendingTxMode::parse(&mode).unwrap_or(PendingTxMode::Auto);
+        }
+
+        if let Ok(mode) = std::env::var("SOURCE_MODE") {
+            config.source_mode = SourceMode::parse(&mode).unwrap_or(SourceMode::Mempool);
+        }
+
+        if let Ok(race) = std::env::var("RACE_MODE") {
+            config.race_mode = race == "1" || race.eq_ignore_ascii_case("true");
+        }
+
+        if let Ok(timeout) = std::env::var("STALL_TIMEOUT_SECS") {
+            config.stall_timeout_secs = timeout.parse().unwrap_or(DEFAULT_STALL_TIMEOUT_SECS);
+        }
+
+        if let Ok(timeout) = std::env::var("DROP_TIMEOUT_SECS") {
+            config.drop_timeout_secs = timeout.parse().unwrap_or(DEFAULT_DROP_TIMEOUT_SECS);
+        }
+
+        if let Ok(interval) = std::env::var("POLL_INTERVAL_MS") {
+            let interval_ms = interval.parse().unwrap_or(DEFAULT_POLL_INTERVAL.as_millis() as u64);
+            config.poll_interval_ms = interval_ms.max(MIN_POLL_INTERVAL.as_millis() as u64);
+        }
+
         Ok(config)
     }
+
+    pub fn fetch_options(&self) -> FetchOptions {
+        FetchOptions {
+            concurrency: self.fetch_concurrency,
+            queue_size: self.fetch_queue_size,
+        }
+    }
 }
 
 impl AppState {
     pub fn new(config: Config) -> Self {
         let max_transactions = config.max_transactions;
+        let race_endpoints = if config.race_mode {
+            race_endpoints(&config.rpc_urls)
+        } else {
+            Vec::new()
+        };
         Self {
             transactions: VecDeque::with_capacity(max_transactions),
             max_transactions,
@@ -98,24 +262,58 @@ impl AppState {
                 transactions_per_second: 0.0,
                 memory_usage_mb: 0.0,
                 last_perf_update: Instant::now(),
+                abi_report: None,
+                fetch: FetchSnapshot::default(),
+                latest_block: None,
+                finalized_block: None,
+                connection: ConnectionState::Connecting,
+                active_endpoint: None,
+                race_endpoints,
+                chain: None,
             },
             config,
             should_quit: false,
             show_new_on_top: true, // Default to showing new transactions on top
+            value_unit: Denomination::Native,
+            amount_format: AmountFormat::default(),
             show_details: false,
             selected_transaction: None,
             details_scroll_offset: 0,
+            show_raw_json: false,
+            selected_raw_json: None,
             filter: FilterState::new(),
             quit_confirmation: false,
             pending_tx_fetch: None,
+            pending_reconnect: false,
+            pending_receipts: Vec::new(),
+            pending_drop_checks: Vec::new(),
+            drop_checks_in_flight: HashSet::new(),
+            pending_by_sender: HashMap::new(),
+            mined_nonces: HashMap::new(),
+            mined_nonce_order: VecDeque::new(),
         }
     }
 
-    pub fn add_transaction(&mut self, tx: Transaction) {
+    pub fn add_transaction(&mut self, mut tx: Transaction) {
+        // Race mode: a later arrival of a buffered transaction only adds its sighting
+        if let Some(sighting) = tx.seen_by.first() {
+            if let Some(existing) = self.transactions.iter_mut().find(|t| t.hash == tx.hash) {
+                existing.add_sighting(sighting.clone());
+                return;
+            }
+        }
+
+        if let Some(block) = tx.block_number {
+            self.stats.latest_block = self.stats.latest_block.max(Some(block));
+        } else {
+            self.track_replacement(&mut tx);
+        }
+
         if self.show_new_on_top {
             // Add new transactions at the front
             if self.transactions.len() >= self.max_transactions {
-                self.transactions.pop_back(); // Remove oldest from back
+                let evicted = self.transactions.pop_back(); // Remove oldest from back
+                self.forget_sender_nonce(evicted);
             }
             self.transactions.push_front(tx);
 
@@ -127,7 +325,8 @@ impl AppState {
         } else {
             // Add new transactions at the back (original behavior)
             if self.transactions.len() >= self.max_transactions {
-                self.transactions.pop_front();
+                let evicted = self.transactions.pop_front();
+                self.forget_sender_nonce(evicted);
                 // Adjust scroll position if we removed a transaction before the current view
                 if self.scroll_state.selected > 0 {
                     self.scroll_state.selected = self.scroll_state.selected.saturating_sub(1);
@@ -229,7 +428,44 @@ impl AppState {
         self.stats.connected = connected;
         if connected {
             self.stats.last_error = None;
+            self.stats.connection = ConnectionState::Connected;
+        }
+    }
+
+    pub fn set_connection_state(&mut self, connection: ConnectionState) {
+        match &connection {
+            ConnectionState::Connected => self.set_connected(true),
+            ConnectionState::Connecting => self.set_error("Connecting to RPC endpoint...".to_string()),
+            ConnectionState::Stalled { .. } => {}
+            ConnectionState::Reconnecting { .. } | ConnectionState::GaveUp { .. } => {
+                self.stats.connected = false;
+            }
         }
+        self.stats.connection = connection;
+    }
+
+    /// Update one race endpoint; the stream counts as connected while any endpoint is
+    pub fn set_race_endpoint(&mut self, index: usize, connected: bool, error: Option<String>) {
+        let Some(endpoint) = self.stats.race_endpoints.get_mut(index) else {
+            return;
+        };
+        endpoint.connected = connected;
+        let name = endpoint.name.clone();
+        let any_connected = self.stats.race_endpoints.iter().any(|e| e.connected);
+        let connection = if any_connected {
+            ConnectionState::Connected
+        } else {
+            ConnectionState::Connecting
+        };
+        self.set_connection_state(connection);
+        if let Some(error) = error {
+            self.stats.last_error = Some(format!("{}: {}", name, error));
+        }
+    }
+
+    /// Ask the connection task to drop the connection and reconnect now
+    pub fn request_reconnect(&mut self) {
+        self.pending_reconnect = true;
     }
 
     pub fn set_error(&mut self, error: String) {
@@ -255,11 +491,29 @@ impl AppState {
         self.scroll_state.selected = 0;
     }
 
+    /// Switch the value unit: native token → Gwei → wei
+    pub fn cycle_value_unit(&mut self) {
+        self.value_unit = self.value_unit.next();
+    }
+
+    pub fn toggle_full_precision(&mut self) {
+        self.amount_format.full_precision = !self.amount_format.full_precision;
+    }
+
+    /// Format a wei amount in `unit` with the current display settings
+    pub fn format_amount(&self, wei: U256, unit: Denomination) -> String {
+        format_amount(wei, unit.decimals(self.stats.chain().decimals), &self.amount_format)
+    }
+
     pub fn clear_transactions(&mut self) {
         self.transactions.clear();
+        self.pending_by_sender.clear();
+        self.mined_nonces.clear();
+        self.mined_nonce_order.clear();
         self.scroll_state.offset = 0;
         self.scroll_state.selected = 0;
         self.selected_transaction = None;
+        self.selected_raw_json = None;
         self.show_details = false;
     }
 
@@ -291,11 +545,170 @@ impl AppState {
         }
     }
 
+    /// Link a pending transaction to the buffered one it replaces, if any
+    fn track_replacement(&mut self, tx: &mut Transaction) {
+        let key = (tx.from.clone(), tx.nonce);
+
+        // Its nonce was already mined by another transaction, so it can never be included
+        if let Some(winner) = self.mined_nonces.get(&key) {
+            if *winner != tx.hash {
+                tx.replaced_by = Some(winner.clone());
+            }
+            return;
+        }
+
+        if let Some(previous) = self.pending_by_sender.get(&key).filter(|hash| **hash != tx.hash) {
+            let replaced = self
+                .transactions
+                .iter_mut()
+                .find(|old| old.hash == *previous && old.block_number.is_none());
+            if let Some(replaced) = replaced {
+                replaced.replaced_by = Some(tx.hash.clone());
+                tx.replaces = Some(replaced.hash.clone());
+                tx.fee_bump_pct = tx.fee_bump_over(replaced);
+            }
+        }
+        self.pending_by_sender.insert(key, tx.hash.clone());
+    }
+
+    /// Remember a mined sender nonce, so a later arrival with it counts as replaced
+    fn remember_mined_nonce(&mut self, key: (String, u64), hash: String) {
+        if self.mined_nonces.insert(key.clone(), hash).is_some() {
+            return;
+        }
+        self.mined_nonce_order.push_back(key);
+        if self.mined_nonce_order.len() > MINED_NONCES_CAPACITY {
+            if let Some(oldest) = self.mined_nonce_order.pop_front() {
+                self.mined_nonces.remove(&oldest);
+            }
+        }
+    }
+
+    fn forget_sender_nonce(&mut self, evicted: Option<Transaction>) {
+        let Some(evicted) = evicted else {
+            return;
+        };
+        let key = (evicted.from, evicted.nonce);
+        if self.pending_by_sender.get(&key) == Some(&evicted.hash) {
+            self.pending_by_sender.remove(&key);
+        }
+    }
+
+    /// Queue transactions still pending after the drop timeout for a receipt lookup.
+    /// The tracker skips blocks after a gap, so a timed-out transaction may have been mined.
+    pub fn check_dropped(&mut self, now_ms: i64) {
+        if self.config.drop_timeout_secs == 0 {
+            return;
+        }
+        let cutoff = now_ms - self.config.drop_timeout_secs as i64 * 1000;
+        for tx in &self.transactions {
+            if tx.block_number.is_none()
+                && tx.replaced_by.is_none()
+                && !tx.dropped
+                && tx.first_seen_ms < cutoff
+                && self.drop_checks_in_flight.insert(tx.hash.clone())
+            {
+                self.pending_drop_checks.push(tx.hash.clone());
+            }
+        }
+    }
+
+    /// Settle timed-out transactions with their receipt lookup: those with a receipt
+    /// were mined, the rest are dropped. A failed lookup (`None`) is retried on the next
+    /// check. A later inclusion still marks a dropped transaction mined.
+    pub fn apply_drop_checks(&mut self, hashes: Vec<String>, receipts: Option<Vec<TransactionReceipt>>) {
+        let checked: HashSet<String> = hashes.into_iter().collect();
+        self.drop_checks_in_flight.retain(|hash| !checked.contains(hash));
+        let Some(receipts) = receipts else {
+            return;
+        };
+
+        self.apply_receipts(receipts);
+        let mut mined = Vec::new();
+        for tx in self.transactions.iter_mut().filter(|tx| checked.contains(&tx.hash)) {
+            if tx.block_number.is_some() {
+                mined.push(((tx.from.clone(), tx.nonce), tx.hash.clone()));
+            } else if tx.replaced_by.is_none() {
+                tx.dropped = true;
+            }
+        }
+        for (key, hash) in mined {
+            self.pending_by_sender.remove(&key);
+            self.remember_mined_nonce(key, hash);
+        }
+        self.refresh_selected_transaction();
+    }
+
+    /// A new block was mined: buffered pending transactions in it are marked mined
+    /// and queued for a receipt lookup. Other pending transactions with a mined
+    /// sender and nonce can never be included and count as replaced.
+    pub fn apply_mined_block(&mut self, block: MinedBlock) {
+        self.stats.latest_block = self.stats.latest_block.max(Some(block.number));
+
+        let mined: HashSet<&str> = block.hashes.iter().map(String::as_str).collect();
+        let included_ms = block.timestamp as i64 * 1000;
+        let mut matched = Vec::new();
+        let mut used_nonces = HashMap::new();
+        for tx in &mut self.transactions {
+            if tx.block_number.is_none() && mined.contains(tx.hash.as_str()) {
+                tx.block_number = Some(block.number);
+                tx.included_ms = Some(included_ms);
+                tx.dropped = false; // Slow rather than dropped
+                tx.replaced_by = None; // Mined before its replacement
+                matched.push(tx.hash.clone());
+                used_nonces.insert((tx.from.clone(), tx.nonce), tx.hash.clone());
+            }
+        }
+
+        for tx in &mut self.transactions {
+            if tx.block_number.is_some() || tx.replaced_by.is_some() {
+                continue;
+            }
+            if let Some(winner) = used_nonces.get(&(tx.from.clone(), tx.nonce)) {
+                tx.replaced_by = Some(winner.clone());
+            }
+        }
+        for (key, hash) in used_nonces {
+            self.pending_by_sender.remove(&key);
+            self.remember_mined_nonce(key, hash);
+        }
+
+        if !matched.is_empty() {
+            self.pending_receipts.push((block.number, matched));
+            self.refresh_selected_transaction();
+        }
+    }
+
+    /// Fill in status, gas used and logs of tracked transactions
+    pub fn apply_receipts(&mut self, receipts: Vec<TransactionReceipt>) {
+        let mut receipts: HashMap<String, TransactionReceipt> = receipts
+            .into_iter()
+            .map(|receipt| (format!("{:#x}", receipt.transaction_hash), receipt))
+            .collect();
+        for tx in &mut self.transactions {
+            if let Some(receipt) = receipts.remove(&tx.hash) {
+                *tx = enhance_with_receipt(tx.clone(), receipt);
+            }
+        }
+        self.refresh_selected_transaction();
+    }
+
+    /// Keep the open details popup in step with updates to its transaction
+    fn refresh_selected_transaction(&mut self) {
+        let Some(selected) = &self.selected_transaction else {
+            return;
+        };
+        if let Some(tx) = self.transactions.iter().find(|tx| tx.hash == selected.hash) {
+            self.selected_transaction = Some(tx.clone());
+            self.prepare_raw_json();
+        }
+    }
+
     /// Get filtered transactions based on current filter
     pub fn get_filtered_transactions(&self) -> Vec<&Transaction> {
         self.transactions
             .iter()
-            .filter(|tx| self.filter.matches(tx))
+            .filter(|tx| self.filter.matches(tx, self.stats.finalized_block))
             .collect()
     }
 
@@ -322,15 +735,57 @@ impl AppState {
             self.selected_transaction = Some(tx.clone());
             self.show_details = true;
             self.details_scroll_offset = 0; // Reset scroll when opening details
+            self.selected_raw_json = None;
+            self.prepare_raw_json();
         }
     }
 
     pub fn hide_transaction_details(&mut self) {
         self.show_details = false;
         self.selected_transaction = None;
+        self.selected_raw_json = None;
         self.details_scroll_offset = 0; // Reset scroll when closing
     }
 
+    /// Open the transaction the shown one replaced or was replaced by, if still buffered
+    pub fn follow_replacement_link(&mut self) {
+        let Some(current) = &self.selected_transaction else {
+            return;
+        };
+        let Some(target) = current.replaced_by.as_ref().or(current.replaces.as_ref()) else {
+            return;
+        };
+        match self.transactions.iter().find(|tx| tx.hash == *target) {
+            Some(tx) => {
+                self.selected_transaction = Some(tx.clone());
+                self.details_scroll_offset = 0;
+                self.selected_raw_json = None;
+                self.prepare_raw_json();
+            }
+            None => self.set_error(format!("Transaction {} is no longer buffered", target)),
+        }
+    }
+
+    /// Switch the details popup between decoded fields and the raw JSON-RPC object
+    pub fn toggle_raw_json(&mut self) {
+        self.show_raw_json = !self.show_raw_json;
+        self.details_scroll_offset = 0;
+        self.prepare_raw_json();
+    }
+
+    /// Pretty-print the selected transaction's JSON-RPC object once, when the raw view
+    /// first shows it, rather than for every received transaction or every frame
+    fn prepare_raw_json(&mut self) {
+        if !self.show_raw_json || self.selected_raw_json.is_some() {
+            return;
+        }
+        self.selected_raw_json = self
+            .selected_transaction
+            .as_ref()
+            .and_then(|tx| tx.raw.as_deref())
+            .and_then(|raw| serde_json::to_string_pretty(raw).ok());
+    }
+
     pub fn scroll_details_up(&mut self) {
         self.details_scroll_offset = self.details_scroll_offset.saturating_sub(1);
     }
@@ -346,4 +801,185 @@ impl AppState {
     pub fn scroll_details_page_down(&mut self) {
         self.details_scroll_offset = self.details_scroll_offset.saturating_add(10);
     }
-}
\ No newline at end of file
+}
+
+/// Race endpoints in `RPC_URLS` order. Endpoints are told apart by index, so providers
+/// sharing a display label (e.g. two Base Mainnet URLs) get a numbered one.
+fn race_endpoints(urls: &[String]) -> Vec<RaceEndpoint> {
+    let labels: Vec<String> = urls.iter().map(|url| crate::rpc::endpoints::format_rpc_url(url)).collect();
+    labels
+        .iter()
+        .enumerate()
+        .map(|(i, label)| {
+            let shared = labels.iter().filter(|other| *other == label).count() > 1;
+            RaceEndpoint {
+                name: if shared { format!("{} #{}", label, i + 1) } else { label.clone() },
+                connected: false,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::race::summarize;
+    use crate::model::{Lifecycle, Sighting};
+
+    fn pending_tx(hash: &str, nonce: u64, max_fee: u128) -> Transaction {
+        Transaction {
+            hash: hash.to_string(),
+            from: "0xabc".to_string(),
+            nonce,
+            gas_limit: 21000,
+            max_fee_per_gas: Some(max_fee),
+            data: "0x".to_string(),
+            first_seen_ms: 1_000,
+            ..Default::default()
+        }
+    }
+
+    fn receipt_in_block_42(hash: &str, success: bool) -> TransactionReceipt {
+        serde_json::from_value(serde_json::json!({
+            "transactionHash": hash, "transactionIndex": "0x0",
+            "blockHash": format!("0x{}", "cd".repeat(32)), "blockNumber": "0x2a",
+            "from": "0x0000000000000000000000000000000000000abc", "to": null, "contractAddress": null,
+            "gasUsed": "0x5208", "effectiveGasPrice": "0x3b9aca00", "cumulativeGasUsed": "0x5208",
+            "status": if success { "0x1" } else { "0x0" }, "type": "0x2", "logs": [],
+            "logsBloom": format!("0x{}", "00".repeat(256)),
+        }))
+        .unwrap()
+    }
+
+    fn find<'a>(state: &'a AppState, hash: &str) -> &'a Transaction {
+        state.transactions.iter().find(|tx| tx.hash == hash).unwrap()
+    }
+
+    #[test]
+    fn test_replacement_and_drop_tracking() {
+        let mut state = AppState::new(Config::default());
+        state.add_transaction(pending_tx("0x1", 5, 100));
+        state.add_transaction(pending_tx("0x2", 5, 125)); // Speed-up
+        state.add_transaction(pending_tx("0x3", 6, 100));
+
+        assert_eq!(find(&state, "0x1").replaced_by.as_deref(), Some("0x2"));
+        assert_eq!(find(&state, "0x1").lifecycle(None), Lifecycle::Replaced);
+        assert_eq!(find(&state, "0x2").replaces.as_deref(), Some("0x1"));
+        assert_eq!(find(&state, "0x2").fee_bump_pct, Some(25.0));
+
+        // The original got mined after all: the speed-up can no longer land
+        state.apply_mined_block(MinedBlock { number: 9, timestamp: 3, hashes: vec!["0x1".to_string()] });
+        assert_eq!(find(&state, "0x1").lifecycle(None), Lifecycle::Mined);
+        assert_eq!(find(&state, "0x1").inclusion_latency_ms(), Some(2_000));
+        assert!(find(&state, "0x1").replaced_by.is_none());
+        assert_eq!(find(&state, "0x2").lifecycle(None), Lifecycle::Replaced);
+        assert_eq!(state.pending_receipts, vec![(9, vec!["0x1".to_string()])]);
+
+        // Timed-out transactions are looked up once before being marked dropped
+        let timeout_ms = state.config.drop_timeout_secs as i64 * 1000;
+        state.check_dropped(1_000 + timeout_ms);
+        assert!(state.pending_drop_checks.is_empty());
+        state.check_dropped(1_001 + timeout_ms);
+        state.check_dropped(1_002 + timeout_ms);
+        assert_eq!(state.pending_drop_checks, vec!["0x3".to_string()]);
+        assert_eq!(find(&state, "0x3").lifecycle(None), Lifecycle::Pending);
+
+        let hashes = std::mem::take(&mut state.pending_drop_checks);
+        state.apply_drop_checks(hashes.clone(), None); // Lookup failed: retried
+        assert_eq!(find(&state, "0x3").lifecycle(None), Lifecycle::Pending);
+        state.check_dropped(1_001 + timeout_ms);
+        assert_eq!(state.pending_drop_checks, hashes);
+
+        let hashes = std::mem::take(&mut state.pending_drop_checks);
+        state.apply_drop_checks(hashes, Some(Vec::new()));
+        assert_eq!(find(&state, "0x3").lifecycle(None), Lifecycle::Dropped);
+    }
+
+    #[test]
+    fn test_same_nonce_arriving_after_it_was_mined() {
+        let mut state = AppState::new(Config::default());
+        state.add_transaction(pending_tx("0x1", 5, 100));
+        state.apply_mined_block(MinedBlock { number: 9, timestamp: 3, hashes: vec!["0x1".to_string()] });
+
+        // A speed-up that reaches us only after the original was mined
+        state.add_transaction(pending_tx("0x2", 5, 125));
+        assert_eq!(find(&state, "0x2").lifecycle(None), Lifecycle::Replaced);
+        assert_eq!(find(&state, "0x2").replaced_by.as_deref(), Some("0x1"));
+        assert!(find(&state, "0x2").replaces.is_none());
+
+        state.check_dropped(1_001 + state.config.drop_timeout_secs as i64 * 1000);
+        assert!(state.pending_drop_checks.is_empty());
+    }
+
+    #[test]
+    fn test_timed_out_transaction_mined_in_a_skipped_block() {
+        let hash = format!("0x{}", "ef".repeat(32));
+        let mut state = AppState::new(Config::default());
+        state.add_transaction(pending_tx(&hash, 0, 100));
+
+        state.check_dropped(1_001 + state.config.drop_timeout_secs as i64 * 1000);
+        let hashes = std::mem::take(&mut state.pending_drop_checks);
+        state.apply_drop_checks(hashes, Some(vec![receipt_in_block_42(&hash, true)]));
+
+        let tx = find(&state, &hash);
+        assert_eq!(tx.lifecycle(None), Lifecycle::Mined);
+        assert_eq!(tx.block_number, Some(42));
+        assert!(!tx.dropped);
+    }
+
+    #[test]
+    fn test_mined_block_then_receipts() {
+        let hash = format!("0x{}", "ab".repeat(32));
+        let mut state = AppState::new(Config::default());
+        state.add_transaction(pending_tx(&hash, 0, 100));
+        state.add_transaction(pending_tx("0x2", 1, 100)); // Not in the block
+
+        state.apply_mined_block(MinedBlock { number: 42, timestamp: 4, hashes: vec![hash.clone()] });
+        assert_eq!(find(&state, &hash).lifecycle(None), Lifecycle::Mined);
+        assert_eq!(find(&state, &hash).inclusion_latency_ms(), Some(3_000));
+        assert_eq!(find(&state, "0x2").lifecycle(None), Lifecycle::Pending);
+        assert_eq!(state.pending_receipts, vec![(42, vec![hash.clone()])]);
+
+        state.apply_receipts(vec![receipt_in_block_42(&hash, false)]);
+
+        let tx = find(&state, &hash);
+        assert_eq!(tx.lifecycle(None), Lifecycle::Failed);
+        assert_eq!(tx.status, Some(false));
+        assert_eq!(tx.gas_used, Some(21_000));
+        assert_eq!(tx.effective_gas_price, Some(1_000_000_000));
+        assert_eq!(tx.block_number, Some(42));
+        assert_eq!(tx.inclusion_latency_ms(), Some(3_000));
+    }
+
+    #[test]
+    fn test_race_endpoints_sharing_a_label() {
+        let config = Config {
+            rpc_urls: vec![
+                "wss://base-mainnet.g.alchemy.com/v2/key".to_string(),
+                "wss://base-mainnet.infura.io/ws/v3/key".to_string(),
+            ],
+            race_mode: true,
+            ..Config::default()
+        };
+        let mut state = AppState::new(config);
+        let names: Vec<&str> = state.stats.race_endpoints.iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(names, ["Base Mainnet #1", "Base Mainnet #2"]);
+
+        let seen_by = |endpoint: usize, received_ms: i64| {
+            let mut tx = pending_tx("0x1", 0, 100);
+            tx.seen_by = vec![Sighting { endpoint, received_ms }];
+            tx
+        };
+        state.add_transaction(seen_by(1, 1_000));
+        state.add_transaction(seen_by(0, 1_040));
+        assert_eq!(find(&state, "0x1").seen_by.len(), 2);
+
+        state.set_race_endpoint(1, true, None);
+        assert!(!state.stats.race_endpoints[0].connected);
+        assert!(state.stats.race_endpoints[1].connected);
+
+        let summary = summarize(&state.stats.race_endpoints, &state.transactions);
+        assert_eq!((summary[0].first, summary[0].avg_lag_ms), (0, Some(40.0)));
+        assert_eq!((summary[1].first, summary[1].avg_lag_ms), (1, None));
+    }
+}
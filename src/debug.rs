@@ -1,4 +1,5 @@
 use crate::model::{FunctionSignature, Transaction};
+use alloy::primitives::U256;
 
 pub fn create_sample_transactions(count: usize) -> Vec<Transaction> {
     let mut transactions = Vec::new();
@@ -24,24 +25,31 @@ pub fn create_sample_transactions(count: usize) -> Vec<Transaction> {
         transactions.push(Transaction {
             hash: format!("0x{:064x}", i),
             from: format!("0x{:040x}", i * 2),
+            nonce: i as u64,
             to: Some(format!("0x{:040x}", i * 3)),
-            value: format!("{:.4}", i as f64 * 0.001),
-            gas_limit: format!("{}", 21000 + i * 100),
-            gas_price: Some(format!("{}", 30 + i)),
+            value: U256::from(i) * U256::from(1_000_000_000_000_000u64), // 0.001 ETH steps
+            gas_limit: 21000 + i as u128 * 100,
+            gas_price: Some(30 + i as u128),
+            tx_type: if i % 2 == 0 { 2 } else { 0 },
+            max_fee_per_gas: (i % 2 == 0).then_some(40 + i as u128),
+            max_priority_fee_per_gas: (i % 2 == 0).then_some(2),
+            chain_id: Some(8453),
             data: data.clone(),
             function_sig: if data.len() > 10 {
                 Some(FunctionSignature {
                     selector: selector.to_string(),
                     name: name.to_string(),
+                    signature: String::new(),
+                    args: Vec::new(),
+                    alternatives: Vec::new(),
+                    guessed: false,
                 })
             } else {
                 None
             },
             timestamp: chrono::Utc::now().timestamp() - (count - i) as i64,
-            block_number: None,
-            status: None,
-            gas_used: None,
-            effective_gas_price: None,
+            first_seen_ms: (chrono::Utc::now().timestamp() - (count - i) as i64) * 1000,
+            ..Default::default()
         });
     }
     transactions
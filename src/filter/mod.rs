@@ -0,0 +1,369 @@
+mod query;
+
+pub use query::{ParseError, Query};
+
+use crate::model::Transaction;
+use regex::{Regex, RegexBuilder};
+
+/// How the filter input is interpreted
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FilterMode {
+    #[default]
+    Query, // Query language, see `query`
+    Regex, // Case-insensitive regex over hash, from, to, function name and calldata
+}
+
+/// The filter input, compiled once per edit rather than per row
+#[derive(Debug, Clone)]
+enum Compiled {
+    Query(Query),
+    Regex(Regex),
+}
+
+/// Filter state management - Single Responsibility: Managing filter state and logic
+#[derive(Debug, Clone, Default)]
+pub struct FilterState {
+    /// Current filter query
+    query: String,
+    /// Whether filter mode is active
+    active: bool,
+    /// Cursor position in the input
+    cursor_position: usize,
+    /// How the query is interpreted
+    mode: FilterMode,
+    /// Query compiled after each edit; `None` when empty or invalid
+    compiled: Option<Compiled>,
+    /// Why the current query doesn't compile
+    error: Option<ParseError>,
+}
+
+impl FilterState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Activate filter mode
+    pub fn activate(&mut self) {
+        self.active = true;
+        self.cursor_position = self.query.len();
+    }
+
+    /// Deactivate filter mode
+    pub fn deactivate(&mut self) {
+        self.active = false;
+    }
+
+    /// Clear the filter
+    pub fn clear(&mut self) {
+        self.query.clear();
+        self.cursor_position = 0;
+        self.recompile();
+    }
+
+    /// Check if filter is active
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+
+    /// Check if filter has a query
+    pub fn has_query(&self) -> bool {
+        !self.query.is_empty()
+    }
+
+    /// Get the current query
+    pub fn query(&self) -> &str {
+        &self.query
+    }
+
+    pub fn mode(&self) -> FilterMode {
+        self.mode
+    }
+
+    /// Switch between query language and regex, keeping the input
+    pub fn toggle_mode(&mut self) {
+        self.mode = match self.mode {
+            FilterMode::Query => FilterMode::Regex,
+            FilterMode::Regex => FilterMode::Query,
+        };
+        self.recompile();
+    }
+
+    /// Parse error of the current query, if any
+    pub fn parse_error(&self) -> Option<&ParseError> {
+        self.error.as_ref()
+    }
+
+    /// Get cursor position
+    pub fn cursor_position(&self) -> usize {
+        self.cursor_position
+    }
+
+    /// Add a character at cursor position
+    pub fn add_char(&mut self, c: char) {
+        self.query.insert(self.cursor_position, c);
+        self.cursor_position += 1;
+        self.recompile();
+    }
+
+    /// Remove character before cursor (backspace)
+    pub fn delete_char_before_cursor(&mut self) {
+        if self.cursor_position > 0 {
+            self.query.remove(self.cursor_position - 1);
+            self.cursor_position -= 1;
+            self.recompile();
+        }
+    }
+
+    /// Remove character at cursor (delete)
+    pub fn delete_char_at_cursor(&mut self) {
+        if self.cursor_position < self.query.len() {
+            self.query.remove(self.cursor_position);
+            self.recompile();
+        }
+    }
+
+    /// Move cursor left
+    pub fn move_cursor_left(&mut self) {
+        self.cursor_position = self.cursor_position.saturating_sub(1);
+    }
+
+    /// Move cursor right
+    pub fn move_cursor_right(&mut self) {
+        self.cursor_position = (self.cursor_position + 1).min(self.query.len());
+    }
+
+    /// Move cursor to start
+    pub fn move_cursor_to_start(&mut self) {
+        self.cursor_position = 0;
+    }
+
+    /// Move cursor to end
+    pub fn move_cursor_to_end(&mut self) {
+        self.cursor_position = self.query.len();
+    }
+
+    /// Check if the query looks like a transaction hash
+    pub fn is_transaction_hash(&self) -> bool {
+        // Transaction hash is 0x followed by 64 hex characters
+        if self.mode == FilterMode::Query && self.query.len() == 66 && self.query.starts_with("0x") {
+            // Check if the rest are valid hex characters
+            self.query[2..].chars().all(|c| c.is_ascii_hexdigit())
+        } else {
+            false
+        }
+    }
+
+    /// Compile the query again after an edit or mode change
+    fn recompile(&mut self) {
+        let compiled = match self.mode {
+            FilterMode::Query => Query::parse(&self.query).map(|query| query.map(Compiled::Query)),
+            FilterMode::Regex if self.query.is_empty() => Ok(None),
+            FilterMode::Regex => compile_regex(&self.query).map(|regex| Some(Compiled::Regex(regex))),
+        };
+        match compiled {
+            Ok(compiled) => {
+                self.compiled = compiled;
+                self.error = None;
+            }
+            Err(error) => {
+                self.compiled = None;
+                self.error = Some(error);
+            }
+        }
+    }
+
+    /// Check if a transaction matches the filter. An invalid query matches nothing.
+    pub fn matches(&self, transaction: &Transaction, finalized_block: Option<u64>) -> bool {
+        if self.error.is_some() {
+            return false;
+        }
+        match &self.compiled {
+            Some(Compiled::Query(query)) => query.matches(transaction, finalized_block),
+            Some(Compiled::Regex(regex)) => {
+                regex.is_match(&transaction.hash)
+                    || regex.is_match(&transaction.from)
+                    || transaction.to.as_deref().is_some_and(|to| regex.is_match(to))
+                    || transaction.function_sig.as_ref().is_some_and(|sig| regex.is_match(&sig.name))
+                    || regex.is_match(&transaction.data)
+            }
+            None => true,
+        }
+    }
+}
+
+/// Build a case-insensitive regex, locating syntax errors for the caret in the popup
+fn compile_regex(pattern: &str) -> Result<Regex, ParseError> {
+    RegexBuilder::new(pattern).case_insensitive(true).build().map_err(|error| {
+        let char_position = |offset: usize| pattern[..offset].chars().count();
+        match regex_syntax::Parser::new().parse(pattern) {
+            Err(regex_syntax::Error::Parse(error)) => {
+                ParseError::new(error.kind().to_string(), char_position(error.span().start.offset))
+            }
+            Err(regex_syntax::Error::Translate(error)) => {
+                ParseError::new(error.kind().to_string(), char_position(error.span().start.offset))
+            }
+            // Valid syntax, but e.g. too large once compiled
+            _ => ParseError::new(error.to_string(), 0),
+        }
+    })
+}
+
+/// Filter statistics for UI display
+#[allow(dead_code)]
+pub struct FilterStats {
+    pub total_transactions: usize,
+    pub filtered_transactions: usize,
+}
+
+#[allow(dead_code)]
+impl FilterStats {
+    pub fn new(total: usize, filtered: usize) -> Self {
+        Self {
+            total_transactions: total,
+            filtered_transactions: filtered,
+        }
+    }
+
+    pub fn display_text(&self) -> String {
+        if self.filtered_transactions == self.total_transactions {
+            format!("{} transactions", self.total_transactions)
+        } else {
+            format!("{}/{} transactions", self.filtered_transactions, self.total_transactions)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{DecodedParam, FunctionSignature};
+    use alloy::primitives::U256;
+
+    fn filter_for(query: &str) -> FilterState {
+        let mut filter = FilterState::new();
+        query.chars().for_each(|c| filter.add_char(c));
+        filter
+    }
+
+    fn sample_transaction() -> Transaction {
+        Transaction {
+            hash: "0xabc".to_string(),
+            from: "0x123456".to_string(),
+            to: Some("0x789".to_string()),
+            value: U256::from(1_000_000_000_000_000_000u128),
+            gas_limit: 21000,
+            gas_price: Some(30),
+            data: "0x".to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_filter_matches() {
+        let tx = sample_transaction();
+        assert!(filter_for("0x123").matches(&tx, None));
+        assert!(filter_for("").matches(&tx, None));
+        assert!(!filter_for("0xdead").matches(&tx, None));
+
+        assert!(filter_for("nonce:0").matches(&tx, None));
+        assert!(!filter_for("nonce:1").matches(&tx, None));
+        assert!(!filter_for("chain:8453").matches(&tx, None));
+    }
+
+    #[test]
+    fn test_query_language() {
+        let tx = sample_transaction();
+        let matches = |query: &str| filter_for(query).matches(&tx, None);
+
+        assert!(matches("from:0x1234 to:0x789"));
+        assert!(!matches("from=0x1234"));
+        assert!(matches("from=0x123456 && value>0.5 && value<=1eth"));
+        assert!(matches("gas>=21000 gasprice<1 type:legacy status:pending"));
+        assert!(!matches("status:failed"));
+        assert!(matches("fn:unk* || fn:swap*"));
+        assert!(matches("!fn:approve && (to:0xdead || value=1)"));
+        assert!(matches("not (nonce>0 or gasused>0)"));
+        assert!(matches("to!=0xdead"));
+
+        // Invalid queries match nothing and report where they failed
+        let invalid = filter_for("value>1 && (from:0x1");
+        assert!(!invalid.matches(&tx, None));
+        assert_eq!(invalid.parse_error().map(|e| e.position), Some(11));
+    }
+
+    #[test]
+    fn test_calldata_filters() {
+        let param = |name: &str, ty: &str, value: &str, children: Vec<DecodedParam>| DecodedParam {
+            name: name.to_string(),
+            ty: ty.to_string(),
+            value: value.to_string(),
+            children,
+        };
+        let mut tx = sample_transaction();
+        tx.data = "0x38ed1739000000000000000000000000000000000000000000000000000000000000c350".to_string();
+        tx.function_sig = Some(FunctionSignature {
+            selector: "0x38ed1739".to_string(),
+            name: "swapExactTokensForTokens".to_string(),
+            signature: String::new(),
+            args: vec![
+                param("amountIn", "uint256", "50000", Vec::new()),
+                param("path", "address[]", "", vec![
+                    param("[0]", "address", "0xc02aaa39b223fe8d0a0e5c4f27ead9083c756cc2", Vec::new()),
+                    param("[1]", "address", "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48", Vec::new()),
+                ]),
+                param("to", "address", "0x123456", Vec::new()),
+            ],
+            alternatives: Vec::new(),
+            guessed: false,
+        });
+        let matches = |query: &str| filter_for(query).matches(&tx, None);
+
+        assert!(matches("fn:swap* has:data has:args selector:0x38ED1739"));
+        assert!(!matches("selector:a9059cbb || is:create"));
+        assert!(matches("arg.address=0xA0B86991C6218B36C1D19D4A2E9EB0CE3606EB48"));
+        assert!(matches("arg.path:0xc02aaa"));
+        assert!(!matches("arg.to:0xc02aaa"));
+        assert!(matches("arg.amountin>=50000 && arg.amountIn<50001"));
+        assert!(matches("arg:0x123456 && arg!=0xdead"));
+        assert!(!filter_for("has:args").matches(&sample_transaction(), None));
+    }
+
+    #[test]
+    fn test_regex_mode() {
+        let mut tx = sample_transaction();
+        tx.data = "0xa9059cbb0000000000000000000000007A250D5630B4CF539739DF2C5DACB4C659F2488D".to_string();
+
+        let mut filter = filter_for("^0x12|7a250d5630b4cf539739df2c5dacb4c659f2488d$");
+        assert!(!filter.matches(&tx, None)); // Just a bare word to the query language
+        filter.toggle_mode();
+        assert_eq!(filter.mode(), FilterMode::Regex);
+        assert!(filter.parse_error().is_none());
+        assert!(filter.matches(&tx, None));
+
+        let mut vanity = filter_for("^0x0000");
+        vanity.toggle_mode();
+        assert!(!vanity.matches(&tx, None));
+
+        let mut invalid = filter_for("from(0x");
+        invalid.toggle_mode();
+        assert!(!invalid.matches(&tx, None));
+        assert_eq!(invalid.parse_error().map(|e| e.position), Some(4));
+    }
+
+    #[test]
+    fn test_filter_input_operations() {
+        let mut filter = FilterState::new();
+
+        filter.add_char('0');
+        filter.add_char('x');
+        assert_eq!(filter.query(), "0x");
+        assert_eq!(filter.cursor_position(), 2);
+
+        filter.move_cursor_left();
+        filter.add_char('_');
+        assert_eq!(filter.query(), "0_x");
+
+        filter.delete_char_before_cursor();
+        assert_eq!(filter.query(), "0x");
+    }
+}
\ No newline at end of file
@@ -0,0 +1,615 @@
+//! Filter query language.
+//!
+//! A query is a boolean expression over field predicates, e.g.
+//! `from:0xabc fn:swap* value>1.5 && !status:failed`:
+//!
+//! - `a b`, `a && b`, `a and b` - both must match
+//! - `a || b`, `a or b` - either matches
+//! - `!a`, `not a` - negation; parentheses group
+//! - `field:value` - substring (addresses, hash) or equality (numbers, names)
+//! - `field=value`, `field!=value` - exact match
+//! - `field>n`, `>=`, `<`, `<=` - numeric comparison
+//! - `arg:x`, `arg.<name or type>:x` - decoded calldata arguments, e.g. `arg.address=0xabc`
+//! - a bare word matches the hash, sender or recipient by substring
+
+use crate::model::{DecodedParam, Lifecycle, Transaction};
+use alloy::primitives::utils::{ParseUnits, Unit};
+use alloy::primitives::U256;
+use std::fmt;
+use std::str::FromStr;
+
+/// Query that failed to parse, with the character offset it failed at
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseError {
+    pub message: String,
+    pub position: usize,
+}
+
+impl ParseError {
+    pub(super) fn new(message: impl Into<String>, position: usize) -> Self {
+        Self { message: message.into(), position }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} (at {})", self.message, self.position + 1)
+    }
+}
+
+/// Parsed filter query
+#[derive(Debug, Clone, PartialEq)]
+pub enum Query {
+    And(Box<Query>, Box<Query>),
+    Or(Box<Query>, Box<Query>),
+    Not(Box<Query>),
+    Predicate(Predicate),
+}
+
+/// A single test against a transaction
+#[derive(Debug, Clone, PartialEq)]
+pub enum Predicate {
+    /// Bare word: substring of hash, from or to
+    Text(String),
+    Address { field: AddressField, exact: bool, value: String },
+    /// Case-insensitive glob over the function name
+    Function(String),
+    Number { field: NumericField, op: Comparison, value: U256 },
+    Status(StatusMatch),
+    /// Lowercase `0x` + 8 hex digits
+    Selector(String),
+    Flag(Flag),
+    /// Decoded calldata argument; `name` matches a parameter's name or type
+    Argument { name: Option<String>, test: ArgumentTest },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AddressField {
+    From,
+    To,
+    Hash,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NumericField {
+    Value, // wei
+    Gas, // gas limit
+    GasUsed,
+    GasPrice, // wei, fee cap for dynamic-fee transactions
+    Nonce,
+    Block,
+    Chain,
+    Type,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Comparison {
+    Eq,
+    Ne,
+    Gt,
+    Ge,
+    Lt,
+    Le,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Flag {
+    Data, // has:data
+    Args, // has:args, calldata was decoded into arguments
+    Create, // is:create
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ArgumentTest {
+    Contains(String),
+    Equals(String),
+    Compare(Comparison, U256),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatusMatch {
+    Is(Lifecycle),
+    Success, // mined or finalized without reverting
+}
+
+impl Query {
+    /// Parse a query; `Ok(None)` for an empty or blank one
+    pub fn parse(input: &str) -> Result<Option<Self>, ParseError> {
+        let tokens = tokenize(input)?;
+        if tokens.is_empty() {
+            return Ok(None);
+        }
+        let mut parser = Parser { tokens, next: 0, end: input.chars().count() };
+        let query = parser.or_expr()?;
+        match parser.peek() {
+            None => Ok(Some(query)),
+            Some(token) if token.kind == TokenKind::RParen => {
+                Err(ParseError::new("unmatched ')'", token.position))
+            }
+            Some(token) => Err(ParseError::new("expected an operator", token.position)),
+        }
+    }
+
+    pub fn matches(&self, tx: &Transaction, finalized_block: Option<u64>) -> bool {
+        match self {
+            Self::And(left, right) => left.matches(tx, finalized_block) && right.matches(tx, finalized_block),
+            Self::Or(left, right) => left.matches(tx, finalized_block) || right.matches(tx, finalized_block),
+            Self::Not(inner) => !inner.matches(tx, finalized_block),
+            Self::Predicate(predicate) => predicate.matches(tx, finalized_block),
+        }
+    }
+}
+
+impl Predicate {
+    fn matches(&self, tx: &Transaction, finalized_block: Option<u64>) -> bool {
+        match self {
+            Self::Text(text) => {
+                contains(&tx.hash, text)
+                    || contains(&tx.from, text)
+                    || tx.to.as_deref().is_some_and(|to| contains(to, text))
+            }
+            Self::Address { field, exact, value } => {
+                let target = match field {
+                    AddressField::From => Some(tx.from.as_str()),
+                    AddressField::To => tx.to.as_deref(),
+                    AddressField::Hash => Some(tx.hash.as_str()),
+                };
+                let Some(target) = target else {
+                    return false;
+                };
+                if *exact {
+                    target.eq_ignore_ascii_case(value)
+                } else {
+                    // Recipients can also be found by their contract label
+                    contains(target, value)
+                        || (*field == AddressField::To
+                            && tx.to_label.as_deref().is_some_and(|label| contains(label, value)))
+                }
+            }
+            Self::Function(pattern) => glob_matches(pattern, &tx.function_name().to_lowercase()),
+            Self::Number { field, op, value } => {
+                field.read(tx).is_some_and(|actual| op.holds(actual, *value))
+            }
+            Self::Status(status) => {
+                let lifecycle = tx.lifecycle(finalized_block);
+                match status {
+                    StatusMatch::Is(expected) => lifecycle == *expected,
+                    StatusMatch::Success => {
+                        matches!(lifecycle, Lifecycle::Mined | Lifecycle::Finalized) && tx.status != Some(false)
+                    }
+                }
+            }
+            Self::Selector(selector) => {
+                // Read from calldata so undecoded calls match too
+                tx.data.get(..10).is_some_and(|prefix| prefix.eq_ignore_ascii_case(selector))
+            }
+            Self::Flag(Flag::Data) => tx.has_data(),
+            Self::Flag(Flag::Args) => tx.function_sig.as_ref().is_some_and(|sig| !sig.args.is_empty()),
+            Self::Flag(Flag::Create) => tx.is_contract_creation(),
+            Self::Argument { name, test } => tx.function_sig.as_ref().is_some_and(|sig| {
+                sig.args.iter().any(|arg| argument_matches(arg, name.as_deref(), test))
+            }),
+        }
+    }
+}
+
+/// Whether `param` or any element nested in it passes `test`. Once a parameter's
+/// name or type matches `name`, all of its elements are candidates.
+fn argument_matches(param: &DecodedParam, name: Option<&str>, test: &ArgumentTest) -> bool {
+    let name = name.filter(|name| {
+        !param.name.eq_ignore_ascii_case(name) && !param.ty.eq_ignore_ascii_case(name)
+    });
+    if !param.children.is_empty() {
+        return param.children.iter().any(|child| argument_matches(child, name, test));
+    }
+    if name.is_some() {
+        return false;
+    }
+
+    // Strings are shown quoted
+    let value = param.value.trim_matches('"');
+    match test {
+        ArgumentTest::Contains(text) => contains(value, text),
+        ArgumentTest::Equals(text) => value.eq_ignore_ascii_case(text),
+        ArgumentTest::Compare(op, expected) => {
+            U256::from_str(value).is_ok_and(|actual| op.holds(actual, *expected))
+        }
+    }
+}
+
+impl NumericField {
+    /// The field's value; `None` when not known yet (e.g. gas used before the receipt)
+    fn read(self, tx: &Transaction) -> Option<U256> {
+        match self {
+            Self::Value => Some(tx.value),
+            Self::Gas => Some(U256::from(tx.gas_limit)),
+            Self::GasUsed => tx.gas_used.map(U256::from),
+            Self::GasPrice => tx.max_price_per_gas().map(U256::from),
+            Self::Nonce => Some(U256::from(tx.nonce)),
+            Self::Block => tx.block_number.map(U256::from),
+            Self::Chain => tx.chain_id.map(U256::from),
+            Self::Type => Some(U256::from(tx.tx_type)),
+        }
+    }
+
+    /// Parse a value written for this field
+    fn parse_value(self, text: &str) -> Option<U256> {
+        match self {
+            Self::Value => parse_amount(text, Unit::ETHER),
+            Self::GasPrice => parse_amount(text, Unit::GWEI),
+            Self::Type => match text.to_lowercase().as_str() {
+                "legacy" => Some(U256::from(0)),
+                "2930" | "accesslist" => Some(U256::from(1)),
+                "1559" | "dynamic" => Some(U256::from(2)),
+                "4844" | "blob" => Some(U256::from(3)),
+                "7702" | "setcode" => Some(U256::from(4)),
+                _ => U256::from_str(text).ok(),
+            },
+            _ => U256::from_str(text).ok(),
+        }
+    }
+}
+
+impl Comparison {
+    fn holds(self, actual: U256, expected: U256) -> bool {
+        match self {
+            Self::Eq => actual == expected,
+            Self::Ne => actual != expected,
+            Self::Gt => actual > expected,
+            Self::Ge => actual >= expected,
+            Self::Lt => actual < expected,
+            Self::Le => actual <= expected,
+        }
+    }
+}
+
+/// Decimal amount with an optional unit suffix (`1.5`, `1.5eth`, `30gwei`, `100wei`)
+fn parse_amount(text: &str, default_unit: Unit) -> Option<U256> {
+    let split = text.find(|c: char| c.is_ascii_alphabetic()).unwrap_or(text.len());
+    let (amount, suffix) = text.split_at(split);
+    if amount.is_empty() || amount.starts_with('-') {
+        return None;
+    }
+    let unit = if suffix.is_empty() {
+        default_unit
+    } else {
+        Unit::from_str(&suffix.to_lowercase()).ok()?
+    };
+    ParseUnits::parse_units(amount, unit).ok().map(ParseUnits::get_absolute)
+}
+
+fn contains(haystack: &str, needle_lower: &str) -> bool {
+    haystack.to_lowercase().contains(needle_lower)
+}
+
+/// `*` matches any run of characters, `?` exactly one
+fn glob_matches(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+    let (mut p, mut t) = (0, 0);
+    let mut backtrack: Option<(usize, usize)> = None; // (pattern after `*`, text it resumes from)
+
+    while t < text.len() {
+        if p < pattern.len() && (pattern[p] == '?' || pattern[p] == text[t]) {
+            p += 1;
+            t += 1;
+        } else if p < pattern.len() && pattern[p] == '*' {
+            backtrack = Some((p + 1, t));
+            p += 1;
+        } else if let Some((star_p, star_t)) = backtrack {
+            // Let the last `*` absorb one more character
+            p = star_p;
+            t = star_t + 1;
+            backtrack = Some((star_p, star_t + 1));
+        } else {
+            return false;
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum TokenKind {
+    LParen,
+    RParen,
+    And,
+    Or,
+    Not,
+    Word(String),
+}
+
+#[derive(Debug, Clone)]
+struct Token {
+    kind: TokenKind,
+    position: usize, // character offset in the query
+}
+
+fn tokenize(input: &str) -> Result<Vec<Token>, ParseError> {
+    let chars: Vec<char> = input.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        let next = chars.get(i + 1).copied();
+        let (kind, len) = match c {
+            _ if c.is_whitespace() => {
+                i += 1;
+                continue;
+            }
+            '(' => (TokenKind::LParen, 1),
+            ')' => (TokenKind::RParen, 1),
+            '&' if next == Some('&') => (TokenKind::And, 2),
+            '|' if next == Some('|') => (TokenKind::Or, 2),
+            '!' if next != Some('=') => (TokenKind::Not, 1),
+            '&' | '|' => return Err(ParseError::new(format!("expected '{}{}'", c, c), i)),
+            _ => {
+                let start = i;
+                let mut end = i;
+                while end < chars.len() && !is_word_boundary(&chars, end) {
+                    end += 1;
+                }
+                let word: String = chars[start..end].iter().collect();
+                let kind = match word.to_lowercase().as_str() {
+                    "and" => TokenKind::And,
+                    "or" => TokenKind::Or,
+                    "not" => TokenKind::Not,
+                    _ => TokenKind::Word(word),
+                };
+                (kind, end - start)
+            }
+        };
+        tokens.push(Token { kind, position: i });
+        i += len;
+    }
+    Ok(tokens)
+}
+
+fn is_word_boundary(chars: &[char], i: usize) -> bool {
+    match chars[i] {
+        c if c.is_whitespace() => true,
+        '(' | ')' => true,
+        '&' | '|' => chars.get(i + 1) == Some(&chars[i]),
+        _ => false,
+    }
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    next: usize,
+    end: usize, // query length, where "unexpected end" errors point
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.next)
+    }
+
+    fn advance(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.next).cloned();
+        self.next += 1;
+        token
+    }
+
+    fn or_expr(&mut self) -> Result<Query, ParseError> {
+        let mut query = self.and_expr()?;
+        while self.peek().is_some_and(|token| token.kind == TokenKind::Or) {
+            self.advance();
+            let right = self.and_expr()?;
+            query = Query::Or(Box::new(query), Box::new(right));
+        }
+        Ok(query)
+    }
+
+    /// Terms next to each other are combined with AND
+    fn and_expr(&mut self) -> Result<Query, ParseError> {
+        let mut query = self.unary()?;
+        loop {
+            match self.peek().map(|token| &token.kind) {
+                Some(TokenKind::And) => {
+                    self.advance();
+                }
+                Some(TokenKind::Not | TokenKind::LParen | TokenKind::Word(_)) => {}
+                _ => break,
+            }
+            let right = self.unary()?;
+            query = Query::And(Box::new(query), Box::new(right));
+        }
+        Ok(query)
+    }
+
+    fn unary(&mut self) -> Result<Query, ParseError> {
+        if self.peek().is_some_and(|token| token.kind == TokenKind::Not) {
+            self.advance();
+            return Ok(Query::Not(Box::new(self.unary()?)));
+        }
+        self.primary()
+    }
+
+    fn primary(&mut self) -> Result<Query, ParseError> {
+        let Some(token) = self.advance() else {
+            return Err(ParseError::new("unexpected end of query", self.end));
+        };
+        match token.kind {
+            TokenKind::LParen => {
+                let query = self.or_expr()?;
+                match self.advance() {
+                    Some(Token { kind: TokenKind::RParen, .. }) => Ok(query),
+                    _ => Err(ParseError::new("missing ')'", token.position)),
+                }
+            }
+            TokenKind::Word(word) => parse_term(&word, token.position),
+            TokenKind::RParen => Err(ParseError::new("unexpected ')'", token.position)),
+            TokenKind::And | TokenKind::Or => Err(ParseError::new("operator needs a term before it", token.position)),
+            TokenKind::Not => unreachable!("handled by unary"),
+        }
+    }
+}
+
+/// Turn a word such as `value>1.5` or `0xabc` into a query term
+fn parse_term(word: &str, position: usize) -> Result<Query, ParseError> {
+    let Some(op_start) = word.find([':', '=', '!', '<', '>']) else {
+        return Ok(Query::Predicate(Predicate::Text(word.to_lowercase())));
+    };
+    let name = &word[..op_start];
+    let op_position = position + name.chars().count();
+    let (op, op_len) = match &word.as_bytes()[op_start..] {
+        [b'!', b'=', ..] => ("!=", 2),
+        [b'>', b'=', ..] => (">=", 2),
+        [b'<', b'=', ..] => ("<=", 2),
+        [b':', ..] => (":", 1),
+        [b'=', ..] => ("=", 1),
+        [b'>', ..] => (">", 1),
+        [b'<', ..] => ("<", 1),
+        _ => return Err(ParseError::new("expected ':', '=', '!=', '<' or '>'", op_position)),
+    };
+    if name.is_empty() {
+        return Err(ParseError::new("missing field name", position));
+    }
+    let value = &word[op_start + op_len..];
+    let value_position = op_position + op_len;
+    if value.is_empty() {
+        return Err(ParseError::new(format!("missing value for '{}'", name), value_position));
+    }
+    let comparison = match op {
+        ":" | "=" => Comparison::Eq,
+        "!=" => Comparison::Ne,
+        ">" => Comparison::Gt,
+        ">=" => Comparison::Ge,
+        "<" => Comparison::Lt,
+        _ => Comparison::Le,
+    };
+
+    let field = name.to_lowercase();
+    if field == "arg" || field.starts_with("arg.") {
+        let arg_name = field.strip_prefix("arg.").map(str::to_string);
+        if arg_name.as_deref() == Some("") {
+            return Err(ParseError::new("missing argument name after 'arg.'", op_position));
+        }
+        let value = value.to_lowercase();
+        let test = match comparison {
+            Comparison::Eq if op == ":" => ArgumentTest::Contains(value),
+            Comparison::Eq | Comparison::Ne => ArgumentTest::Equals(value),
+            _ => ArgumentTest::Compare(
+                comparison,
+                U256::from_str(&value)
+                    .map_err(|_| ParseError::new(format!("invalid number for '{}'", name), value_position))?,
+            ),
+        };
+        let predicate = Query::Predicate(Predicate::Argument { name: arg_name, test });
+        return Ok(match comparison {
+            Comparison::Ne => Query::Not(Box::new(predicate)),
+            _ => predicate,
+        });
+    }
+
+    let numeric_field = match field.as_str() {
+        "value" => Some(NumericField::Value),
+        "gas" | "gaslimit" => Some(NumericField::Gas),
+        "gasused" => Some(NumericField::GasUsed),
+        "gasprice" => Some(NumericField::GasPrice),
+        "nonce" => Some(NumericField::Nonce),
+        "block" => Some(NumericField::Block),
+        "chain" => Some(NumericField::Chain),
+        "type" => Some(NumericField::Type),
+        _ => None,
+    };
+    if let Some(numeric) = numeric_field {
+        let value = numeric
+            .parse_value(value)
+            .ok_or_else(|| ParseError::new(format!("invalid value for '{}'", name), value_position))?;
+        return Ok(Query::Predicate(Predicate::Number { field: numeric, op: comparison, value }));
+    }
+
+    let predicate = match field.as_str() {
+        "from" | "to" | "hash" => {
+            let field = match field.as_str() {
+                "from" => AddressField::From,
+                "to" => AddressField::To,
+                _ => AddressField::Hash,
+            };
+            Predicate::Address { field, exact: op != ":", value: value.to_lowercase() }
+        }
+        "fn" | "function" => Predicate::Function(value.to_lowercase()),
+        "status" => parse_status(value)
+            .map(Predicate::Status)
+            .ok_or_else(|| ParseError::new(format!("unknown status '{}'", value), value_position))?,
+        "selector" => parse_selector(value)
+            .map(Predicate::Selector)
+            .ok_or_else(|| ParseError::new("selector must be 4 bytes of hex, e.g. 0xa9059cbb", value_position))?,
+        "has" | "is" => {
+            let flag = match (field.as_str(), value.to_lowercase().as_str()) {
+                ("has", "data") => Flag::Data,
+                ("has", "args") => Flag::Args,
+                ("is", "create") => Flag::Create,
+                _ => return Err(ParseError::new(format!("unknown flag '{}'", value), value_position)),
+            };
+            Predicate::Flag(flag)
+        }
+        _ => return Err(ParseError::new(format!("unknown field '{}'", name), position)),
+    };
+
+    // Text fields only support equality
+    match comparison {
+        Comparison::Eq => Ok(Query::Predicate(predicate)),
+        Comparison::Ne => Ok(Query::Not(Box::new(Query::Predicate(predicate)))),
+        _ => Err(ParseError::new(format!("'{}' can't be compared with '{}'", name, op), op_position)),
+    }
+}
+
+/// `0xa9059cbb` or `a9059cbb`, normalized to lowercase with `0x`
+fn parse_selector(value: &str) -> Option<String> {
+    let digits = value.strip_prefix("0x").unwrap_or(value);
+    (digits.len() == 8 && digits.chars().all(|c| c.is_ascii_hexdigit()))
+        .then(|| format!("0x{}", digits.to_lowercase()))
+}
+
+fn parse_status(value: &str) -> Option<StatusMatch> {
+    let lifecycle = match value.to_lowercase().as_str() {
+        "pending" => Lifecycle::Pending,
+        "mined" | "included" => Lifecycle::Mined,
+        "final" | "finalized" => Lifecycle::Finalized,
+        "failed" | "reverted" => Lifecycle::Failed,
+        "replaced" => Lifecycle::Replaced,
+        "dropped" => Lifecycle::Dropped,
+        "success" | "ok" => return Some(StatusMatch::Success),
+        _ => return None,
+    };
+    Some(StatusMatch::Is(lifecycle))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn error_at(input: &str) -> (String, usize) {
+        let error = Query::parse(input).unwrap_err();
+        (error.message, error.position)
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(Query::parse("  ").unwrap(), None);
+        assert_eq!(error_at("value>"), ("missing value for 'value'".to_string(), 6));
+        assert_eq!(error_at("fn>swap"), ("'fn' can't be compared with '>'".to_string(), 2));
+        assert_eq!(error_at("colour:red").1, 0);
+        assert_eq!(error_at("gas>lots").1, 4);
+        assert_eq!(error_at("a || ").1, 5);
+        assert_eq!(error_at("a)").1, 1);
+        assert_eq!(error_at("a & b").1, 2);
+        assert_eq!(error_at("selector:0x1234").1, 9);
+        assert_eq!(error_at("has:logs").1, 4);
+        assert_eq!(error_at("arg.amount>lots").1, 11);
+    }
+
+    #[test]
+    fn test_precedence_and_globs() {
+        let query = Query::parse("a b || c").unwrap().unwrap();
+        assert!(matches!(query, Query::Or(left, _) if matches!(*left, Query::And(..))));
+
+        assert!(glob_matches("swap*", "swapexacttokensforeth"));
+        assert!(glob_matches("*transfer*", "safetransferfrom"));
+        assert!(glob_matches("appro?e", "approve"));
+        assert!(!glob_matches("swap", "swapexacttokensforeth"));
+    }
+}
@@ -8,7 +8,7 @@ mod ui;
 mod debug;
 
 use anyhow::Result;
-use app::{handle_event, AppEvent, AppState, Config};
+use app::{handle_event, AppEvent, AppState, Config, ConnectionState};
 use crossterm::{
     event::{Event, EventStream},
     execute,
@@ -17,6 +17,7 @@ use crossterm::{
 use futures::StreamExt;
 use ratatui::{backend::CrosstermBackend, Terminal};
 use std::io;
+use std::sync::{Arc, RwLock};
 use std::time::{Duration, Instant};
 use tokio::sync::mpsc;
 use tokio::time::{interval, sleep};
@@ -31,7 +32,20 @@ const FRAME_TIME_MS: u64 = 1000 / MAX_FPS;
 #[tokio::main]
 async fn main() -> Result<()> {
     let config = Config::load()?;
+
+    // Load user ABIs, contract bindings and the signature database before any transaction is decoded
+    let (registry, abi_report) = model::registry::SignatureRegistry::load(
+        config.abi_dir.as_deref(),
+        config.signature_db.as_deref(),
+        config.contracts_file.as_deref(),
+    )?;
+    model::registry::init(registry);
+    model::chains::init(model::chains::ChainRegistry::load(config.chains_file.as_deref())?);
+
     let mut app_state = AppState::new(config.clone());
+    if config.abi_dir.is_some() || config.signature_db.is_some() || config.contracts_file.is_some() {
+        app_state.stats.abi_report = Some(abi_report);
+    }
     let mut terminal = setup_terminal()?;
 
     // Initialize debug mode if enabled
@@ -42,9 +56,35 @@ async fn main() -> Result<()> {
     let (tx_sender, tx_receiver) = mpsc::channel(1000);
     let (event_sender, event_receiver) = mpsc::unbounded_channel();
 
+    // Connection shared by the subscription and on-demand lookups
+    let rpc_handles = RpcHandles::new();
+
+    // Control channel to the connection task. Only the main loop holds the sender,
+    // so the task sees the channel close when the loop exits.
+    let (rpc_command_sender, rpc_commands) = mpsc::unbounded_channel();
+
     // Spawn RPC connection task (unless in debug simulation mode)
     if std::env::var("DEBUG_MODE").unwrap_or_default() != "1" {
-        spawn_rpc_task(config.rpc_url.clone(), tx_sender.clone(), event_sender.clone());
+        if config.race_mode {
+            spawn_race_tasks(&config, &rpc_handles, tx_sender.clone(), event_sender.clone());
+        } else {
+            spawn_rpc_task(
+                config.clone(),
+                rpc_handles.clone(),
+                rpc_commands,
+                tx_sender.clone(),
+                event_sender.clone(),
+            );
+        }
+
+        // Mined blocks already carry receipts; pending transactions need following
+        if config.source_mode == rpc::SourceMode::Mempool {
+            rpc::spawn_lifecycle_tracker(
+                rpc_handles.client.clone(),
+                Duration::from_millis(config.poll_interval_ms),
+                event_sender.clone(),
+            );
+        }
     } else {
         // Spawn debug transaction generator if in debug simulation mode
         #[cfg(debug_assertions)]
@@ -52,6 +92,7 @@ async fn main() -> Result<()> {
 
         // Keep event_sender alive in debug mode
         let _ = event_sender;
+        drop(rpc_commands);
     }
 
     // Run main event loop
@@ -61,7 +102,8 @@ async fn main() -> Result<()> {
         tx_receiver,
         event_receiver,
         event_sender.clone(),
-        config.rpc_url.clone(),
+        rpc_handles,
+        rpc_command_sender,
     ).await;
 
     restore_terminal(&mut terminal)?;
@@ -74,7 +116,8 @@ async fn run_event_loop(
     mut tx_receiver: mpsc::Receiver<model::Transaction>,
     mut event_receiver: mpsc::UnboundedReceiver<AppEvent>,
     event_sender: mpsc::UnboundedSender<AppEvent>,
-    rpc_url: String,
+    rpc_handles: RpcHandles,
+    rpc_commands: mpsc::UnboundedSender<rpc::RpcCommand>,
 ) -> Result<()> {
     let mut input_events = EventStream::new();
     let mut render_interval = interval(Duration::from_millis(RENDER_INTERVAL_MS));
@@ -91,7 +134,17 @@ async fn run_event_loop(
 
                 // Check if we need to fetch a transaction
                 if let Some(tx_hash) = app_state.pending_tx_fetch.take() {
-                    spawn_tx_fetch_task(rpc_url.clone(), tx_hash, event_sender.clone());
+                    let rpc_url = app_state
+                        .stats
+                        .active_endpoint
+                        .as_ref()
+                        .map_or_else(|| app_state.config.rpc_url.clone(), |endpoint| endpoint.url.clone());
+                    spawn_tx_fetch_task(rpc_handles.client.clone(), rpc_url, tx_hash, event_sender.clone());
+                }
+
+                // Forward a forced reconnect to the connection task
+                if std::mem::take(&mut app_state.pending_reconnect) {
+                    let _ = rpc_commands.send(rpc::RpcCommand::Reconnect);
                 }
 
                 if app_state.should_quit {
@@ -113,6 +166,11 @@ async fn run_event_loop(
             Some(event) = event_receiver.recv() => {
                 handle_event(event, app_state).await?;
                 render_state.request_render();
+
+                // Look up receipts of transactions the tracker saw mined
+                for (block_number, hashes) in app_state.pending_receipts.drain(..) {
+                    spawn_receipt_fetch_task(rpc_handles.client.clone(), block_number, hashes, event_sender.clone());
+                }
             }
 
             // Render tick
@@ -125,6 +183,28 @@ async fn run_event_loop(
                     render_state.request_render();
                 }
 
+                // Pending transactions that never got mined, confirmed by receipt lookup
+                app_state.check_dropped(chrono::Utc::now().timestamp_millis());
+                if !app_state.pending_drop_checks.is_empty() {
+                    let hashes = std::mem::take(&mut app_state.pending_drop_checks);
+                    spawn_drop_check_task(rpc_handles.client.clone(), hashes, event_sender.clone());
+                }
+
+                // Pick up fetch pipeline counters
+                let fetch = rpc_handles.fetch_counters.snapshot();
+                if fetch != app_state.stats.fetch {
+                    app_state.stats.fetch = fetch;
+                    render_state.request_render();
+                }
+
+                // Keep the reconnect countdown and stall timer ticking
+                if matches!(
+                    app_state.stats.connection,
+                    ConnectionState::Reconnecting { .. } | ConnectionState::Stalled { .. }
+                ) {
+                    render_state.request_render();
+                }
+
                 // Render if needed and not too frequent
                 if render_state.should_render() {
                     terminal.draw(|f| ui::render_ui(f, app_state))?;
@@ -135,6 +215,23 @@ async fn run_event_loop(
     }
 }
 
+/// Handles shared between the connection task and the main loop
+#[derive(Clone)]
+struct RpcHandles {
+    /// Live client, used for on-demand lookups
+    client: rpc::SharedClient,
+    fetch_counters: Arc<rpc::FetchCounters>,
+}
+
+impl RpcHandles {
+    fn new() -> Self {
+        Self {
+            client: Arc::new(RwLock::new(None)),
+            fetch_counters: Arc::new(rpc::FetchCounters::default()),
+        }
+    }
+}
+
 /// Manages rendering state to optimize frame rate
 struct RenderState {
     needs_render: bool,
@@ -205,74 +302,331 @@ impl TransactionBatch {
 }
 
 fn spawn_tx_fetch_task(
+    shared_client: rpc::SharedClient,
     rpc_url: String,
     tx_hash: String,
     event_sender: mpsc::UnboundedSender<AppEvent>,
 ) {
     tokio::spawn(async move {
-        match rpc::RpcClient::connect(&rpc_url).await {
-            Ok(client) => {
-                match client.fetch_transaction_by_hash(&tx_hash).await {
-                    Ok(Some(tx)) => {
-                        let _ = event_sender.send(AppEvent::TransactionFetched(tx));
-                    }
-                    Ok(None) => {
-                        let _ = event_sender.send(AppEvent::TransactionNotFound(tx_hash));
-                    }
-                    Err(e) => {
-                        let _ = event_sender.send(AppEvent::Disconnected(
-                            format!("Failed to fetch transaction: {}", e)
-                        ));
-                    }
+        // Reuse the live connection; only dial a new one if there is none
+        let client = match rpc::current_client(&shared_client) {
+            Some(client) => client,
+            None => match rpc::RpcClient::connect(&rpc_url).await {
+                Ok(client) => client,
+                Err(e) => {
+                    let _ = event_sender.send(AppEvent::Disconnected(
+                        format!("Connection error: {}", e)
+                    ));
+                    return;
                 }
+            },
+        };
+
+        match client.fetch_transaction_by_hash(&tx_hash).await {
+            Ok(Some(tx)) => {
+                let _ = event_sender.send(AppEvent::TransactionFetched(tx));
+            }
+            Ok(None) => {
+                let _ = event_sender.send(AppEvent::TransactionNotFound(tx_hash));
             }
             Err(e) => {
                 let _ = event_sender.send(AppEvent::Disconnected(
-                    format!("Connection error: {}", e)
+                    format!("Failed to fetch transaction: {}", e)
                 ));
             }
         }
     });
 }
 
+fn spawn_drop_check_task(
+    shared_client: rpc::SharedClient,
+    hashes: Vec<String>,
+    event_sender: mpsc::UnboundedSender<AppEvent>,
+) {
+    tokio::spawn(async move {
+        // Without a connection there is nothing to ask; a later inclusion still marks them mined
+        let receipts = match rpc::current_client(&shared_client) {
+            Some(client) => client.fetch_receipts_by_hash(&hashes).await.ok(),
+            None => Some(Vec::new()),
+        };
+        let _ = event_sender.send(AppEvent::DropChecked { hashes, receipts });
+    });
+}
+
+fn spawn_receipt_fetch_task(
+    shared_client: rpc::SharedClient,
+    block_number: u64,
+    hashes: Vec<String>,
+    event_sender: mpsc::UnboundedSender<AppEvent>,
+) {
+    tokio::spawn(async move {
+        let Some(client) = rpc::current_client(&shared_client) else {
+            return;
+        };
+        // A missed receipt leaves the transaction mined without status; not worth an error
+        if let Ok(receipts) = client.fetch_receipts(block_number, &hashes).await {
+            if !receipts.is_empty() {
+                let _ = event_sender.send(AppEvent::ReceiptsFetched(receipts));
+            }
+        }
+    });
+}
+
 fn spawn_rpc_task(
-    rpc_url: String,
+    config: Config,
+    handles: RpcHandles,
+    mut commands: mpsc::UnboundedReceiver<rpc::RpcCommand>,
     tx_sender: mpsc::Sender<model::Transaction>,
     event_sender: mpsc::UnboundedSender<AppEvent>,
 ) {
     tokio::spawn(async move {
+        let policy = rpc::ReconnectPolicy::new(
+            config.reconnect_attempts,
+            Duration::from_millis(config.reconnect_delay),
+        );
+        let mut endpoints = rpc::EndpointSet::new(&config.rpc_urls);
+        let mut attempt = 0;
+
         loop {
-            let _ = event_sender.send(AppEvent::Disconnected("Connecting to RPC endpoint...".to_string()));
-
-            match rpc::RpcClient::connect(&rpc_url).await {
-                Ok(client) => {
-                    let _ = event_sender.send(AppEvent::Connected);
-
-                    match client.subscribe_pending_txs().await {
-                        Ok(mut rx) => {
-                            while let Some(tx) = rx.recv().await {
-                                if tx_sender.send(tx).await.is_err() {
-                                    break; // Main loop has exited
-                                }
-                            }
-                        }
-                        Err(e) => {
-                            let _ = event_sender.send(AppEvent::Disconnected(
-                                format!("Subscription error: {}", e)
-                            ));
-                        }
+            let _ = event_sender.send(AppEvent::Endpoint(endpoints.active()));
+            let _ = event_sender.send(AppEvent::Connection(ConnectionState::Connecting));
+
+            // A command cancels the connection in progress, dropping its subscription
+            let subscribed = tokio::select! {
+                subscribed = stream_transactions(&config, &mut endpoints, &handles, &tx_sender, &event_sender, &StatusReporter::Main) => Some(subscribed),
+                command = commands.recv() => match command {
+                    Some(rpc::RpcCommand::Reconnect) => None,
+                    None => return, // Main loop has exited
+                },
+            };
+            set_shared_client(&handles.client, None);
+
+            // Forced reconnects retry the same endpoint straight away
+            let Some(subscribed) = subscribed else {
+                attempt = 0;
+                continue;
+            };
+
+            // A working subscription resets the backoff
+            if subscribed {
+                attempt = 0;
+            }
+
+            // Fail over to the next healthy endpoint without waiting
+            endpoints.record_failure();
+            if endpoints.failover() {
+                continue;
+            }
+
+            // Every endpoint has failed: back off before another round
+            attempt += 1;
+            if policy.gives_up(attempt) {
+                let _ = event_sender.send(AppEvent::Connection(ConnectionState::GaveUp {
+                    attempts: policy.max_attempts(),
+                }));
+                if commands.recv().await.is_none() {
+                    return;
+                }
+                attempt = 0;
+                endpoints.restart_round();
+                continue;
+            }
+
+            let delay = policy.delay(attempt);
+            let _ = event_sender.send(AppEvent::Connection(ConnectionState::Reconnecting {
+                attempt,
+                max_attempts: policy.max_attempts(),
+                retry_at: Instant::now() + delay,
+            }));
+
+            // A reconnect command skips the rest of the wait
+            tokio::select! {
+                _ = sleep(delay) => {}
+                command = commands.recv() => {
+                    if command.is_none() {
+                        return;
                     }
+                    attempt = 0;
                 }
-                Err(e) => {
-                    let _ = event_sender.send(AppEvent::Disconnected(
-                        format!("Connection error: {}", e)
-                    ));
+            }
+            endpoints.restart_round();
+        }
+    });
+}
+
+/// Race mode: one connection per endpoint, all feeding the same transaction stream
+fn spawn_race_tasks(
+    config: &Config,
+    handles: &RpcHandles,
+    tx_sender: mpsc::Sender<model::Transaction>,
+    event_sender: mpsc::UnboundedSender<AppEvent>,
+) {
+    for (index, url) in config.rpc_urls.iter().enumerate() {
+        let mut endpoints = rpc::EndpointSet::new(std::slice::from_ref(url));
+        let reporter = StatusReporter::Race(index);
+        let config = config.clone();
+        let handles = handles.clone();
+        let tx_sender = tx_sender.clone();
+        let event_sender = event_sender.clone();
+
+        tokio::spawn(async move {
+            // Each endpoint retries on its own and never gives up
+            let policy = rpc::ReconnectPolicy::new(0, Duration::from_millis(config.reconnect_delay));
+            let mut attempt = 0;
+
+            loop {
+                if stream_transactions(&config, &mut endpoints, &handles, &tx_sender, &event_sender, &reporter).await {
+                    attempt = 0;
+                }
+                if tx_sender.is_closed() {
+                    return; // Main loop has exited
+                }
+                attempt += 1;
+                sleep(policy.delay(attempt)).await;
+            }
+        });
+    }
+}
+
+/// Where a connection reports its progress
+enum StatusReporter {
+    /// The single failover connection
+    Main,
+    /// One endpoint in race mode, by its index in `RPC_URLS`; its transactions are tagged with it
+    Race(usize),
+}
+
+impl StatusReporter {
+    fn connected(&self, event_sender: &mpsc::UnboundedSender<AppEvent>) {
+        let event = match self {
+            Self::Main => AppEvent::Connection(ConnectionState::Connected),
+            Self::Race(index) => AppEvent::RaceEndpoint { index: *index, connected: true, error: None },
+        };
+        let _ = event_sender.send(event);
+    }
+
+    fn stalled(&self, event_sender: &mpsc::UnboundedSender<AppEvent>, since: Instant) {
+        let event = match self {
+            Self::Main => AppEvent::Connection(ConnectionState::Stalled { since }),
+            Self::Race(index) => AppEvent::RaceEndpoint {
+                index: *index,
+                connected: true,
+                error: Some("stalled".to_string()),
+            },
+        };
+        let _ = event_sender.send(event);
+    }
+
+    fn failed(&self, event_sender: &mpsc::UnboundedSender<AppEvent>, error: String) {
+        let event = match self {
+            Self::Main => AppEvent::Disconnected(error),
+            Self::Race(index) => AppEvent::RaceEndpoint { index: *index, connected: false, error: Some(error) },
+        };
+        let _ = event_sender.send(event);
+    }
+}
+
+/// Connect to the active endpoint and forward transactions until the subscription ends.
+/// Returns whether the subscription was established.
+async fn stream_transactions(
+    config: &Config,
+    endpoints: &mut rpc::EndpointSet,
+    handles: &RpcHandles,
+    tx_sender: &mpsc::Sender<model::Transaction>,
+    event_sender: &mpsc::UnboundedSender<AppEvent>,
+    reporter: &StatusReporter,
+) -> bool {
+    let started = Instant::now();
+    let client = match rpc::RpcClient::connect(endpoints.active_url()).await {
+        Ok(client) => client.with_poll_interval(Duration::from_millis(config.poll_interval_ms)),
+        Err(e) => {
+            reporter.failed(event_sender, format!("Connection error: {}", e));
+            return false;
+        }
+    };
+
+    let subscription = match config.source_mode {
+        rpc::SourceMode::Mempool => {
+            client
+                .subscribe_pending_txs(config.pending_tx_mode, config.fetch_options(), handles.fetch_counters.clone())
+                .await
+        }
+        rpc::SourceMode::Blocks => client.subscribe_block_txs().await,
+    };
+
+    let mut rx = match subscription {
+        Ok(rx) => rx,
+        Err(e) => {
+            reporter.failed(event_sender, format!("Subscription error: {}", e));
+            return false;
+        }
+    };
+
+    endpoints.record_connected(started.elapsed());
+    reporter.connected(event_sender);
+    let _ = event_sender.send(AppEvent::ChainDetected(client.chain_id()));
+    set_shared_client(&handles.client, Some(client.clone()));
+
+    // Watch for a subscription that stays open but goes quiet
+    let mut watchdog = (config.stall_timeout_secs > 0)
+        .then(|| rpc::StallWatchdog::new(Duration::from_secs(config.stall_timeout_secs)));
+    let ping_interval = watchdog
+        .as_ref()
+        .map_or(Duration::from_secs(3600), |watchdog| watchdog.ping_interval());
+    let mut heartbeat = interval(ping_interval);
+
+    loop {
+        tokio::select! {
+            tx = rx.recv() => {
+                let Some(mut tx) = tx else {
+                    break; // Subscription ended
+                };
+                endpoints.record_transaction();
+                if watchdog.as_mut().is_some_and(|watchdog| watchdog.record_activity()) {
+                    reporter.connected(event_sender);
+                }
+                if let StatusReporter::Race(index) = reporter {
+                    tx.seen_by = vec![model::Sighting {
+                        endpoint: *index,
+                        received_ms: chrono::Utc::now().timestamp_millis(),
+                    }];
+                }
+                if tx_sender.send(tx).await.is_err() {
+                    return true; // Main loop has exited
                 }
             }
 
-            sleep(Duration::from_secs(5)).await;
+            _ = heartbeat.tick(), if watchdog.is_some() => {
+                let _ = client.ping(ping_interval).await;
+                if !client.health().is_healthy() {
+                    reporter.failed(event_sender, "Node stopped answering pings".to_string());
+                    return true;
+                }
+
+                match watchdog.as_mut().map(|watchdog| watchdog.check(Instant::now())) {
+                    Some(rpc::StallVerdict::Stalled { since }) => reporter.stalled(event_sender, since),
+                    Some(rpc::StallVerdict::Reconnect { idle }) => {
+                        reporter.failed(
+                            event_sender,
+                            format!("Stalled: no transactions for {}s", idle.as_secs()),
+                        );
+                        return true;
+                    }
+                    _ => {}
+                }
+            }
         }
-    });
+    }
+
+    reporter.failed(event_sender, "Connection lost".to_string());
+    true
+}
+
+/// The live client, if connected and healthy
+fn set_shared_client(shared_client: &rpc::SharedClient, client: Option<rpc::RpcClient>) {
+    if let Ok(mut guard) = shared_client.write() {
+        *guard = client;
+    }
 }
 
 #[cfg(debug_assertions)]
@@ -0,0 +1,166 @@
+use anyhow::{Context, Result};
+use once_cell::sync::{Lazy, OnceCell};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Built-in chain: (id, name, native symbol, block time in ms, explorer transaction URL)
+type BuiltinChain = (u64, &'static str, &'static str, Option<u64>, Option<&'static str>);
+
+const BUILTIN_CHAINS: &[BuiltinChain] = &[
+    (1, "Ethereum", "ETH", Some(12_000), Some("https://etherscan.io/tx/{hash}")),
+    (10, "OP Mainnet", "ETH", Some(2_000), Some("https://optimistic.etherscan.io/tx/{hash}")),
+    (56, "BNB Smart Chain", "BNB", Some(3_000), Some("https://bscscan.com/tx/{hash}")),
+    (100, "Gnosis", "XDAI", Some(5_000), Some("https://gnosisscan.io/tx/{hash}")),
+    (137, "Polygon", "POL", Some(2_000), Some("https://polygonscan.com/tx/{hash}")),
+    (324, "zkSync Era", "ETH", Some(1_000), Some("https://explorer.zksync.io/tx/{hash}")),
+    (8453, "Base", "ETH", Some(2_000), Some("https://basescan.org/tx/{hash}")),
+    (17000, "Holesky", "ETH", Some(12_000), Some("https://holesky.etherscan.io/tx/{hash}")),
+    (31337, "Local (Anvil/Hardhat)", "ETH", None, None),
+    (42161, "Arbitrum One", "ETH", Some(250), Some("https://arbiscan.io/tx/{hash}")),
+    (43114, "Avalanche C-Chain", "AVAX", Some(2_000), Some("https://snowtrace.io/tx/{hash}")),
+    (59144, "Linea", "ETH", Some(2_000), Some("https://lineascan.build/tx/{hash}")),
+    (84532, "Base Sepolia", "ETH", Some(2_000), Some("https://sepolia.basescan.org/tx/{hash}")),
+    (534352, "Scroll", "ETH", Some(3_000), Some("https://scrollscan.com/tx/{hash}")),
+    (11155111, "Sepolia", "ETH", Some(12_000), Some("https://sepolia.etherscan.io/tx/{hash}")),
+];
+
+static CHAINS: OnceCell<ChainRegistry> = OnceCell::new();
+
+/// Shown until the endpoint reports its chain ID
+static FALLBACK: Lazy<ChainInfo> = Lazy::new(|| ChainInfo {
+    id: 0,
+    name: "Unknown chain".to_string(),
+    symbol: default_symbol(),
+    decimals: default_decimals(),
+    block_time_ms: None,
+    explorer_tx_url: None,
+});
+
+/// Install the chain registry. Must be called before any chain lookup to take effect.
+pub fn init(registry: ChainRegistry) {
+    let _ = CHAINS.set(registry);
+}
+
+/// The active chain registry (built-in chains if none was installed)
+pub fn chains() -> &'static ChainRegistry {
+    CHAINS.get_or_init(ChainRegistry::builtin)
+}
+
+/// Chain details to use before the chain ID is known
+pub fn fallback() -> &'static ChainInfo {
+    &FALLBACK
+}
+
+/// Display details of an EVM chain
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct ChainInfo {
+    pub id: u64,
+    pub name: String,
+    #[serde(default = "default_symbol")]
+    pub symbol: String, // Native token
+    #[serde(default = "default_decimals")]
+    pub decimals: u8,
+    #[serde(default)]
+    pub block_time_ms: Option<u64>,
+    #[serde(default)]
+    pub explorer_tx_url: Option<String>, // `{hash}` is replaced by the transaction hash
+}
+
+fn default_symbol() -> String {
+    "ETH".to_string()
+}
+
+fn default_decimals() -> u8 {
+    18
+}
+
+impl ChainInfo {
+    /// Link to a transaction on the chain's block explorer
+    pub fn explorer_link(&self, hash: &str) -> Option<String> {
+        self.explorer_tx_url
+            .as_ref()
+            .map(|template| template.replace("{hash}", hash))
+    }
+}
+
+/// Chain ID to chain details, built-in and from the user's chains file
+pub struct ChainRegistry {
+    chains: HashMap<u64, ChainInfo>,
+}
+
+impl ChainRegistry {
+    pub fn builtin() -> Self {
+        let chains = BUILTIN_CHAINS
+            .iter()
+            .map(|&(id, name, symbol, block_time_ms, explorer)| {
+                let info = ChainInfo {
+                    id,
+                    name: name.to_string(),
+                    symbol: symbol.to_string(),
+                    decimals: default_decimals(),
+                    block_time_ms,
+                    explorer_tx_url: explorer.map(str::to_string),
+                };
+                (id, info)
+            })
+            .collect();
+        Self { chains }
+    }
+
+    /// Built-in chains plus the entries of a JSON chains file, which take precedence
+    pub fn load(chains_file: Option<&Path>) -> Result<Self> {
+        let mut registry = Self::builtin();
+        if let Some(path) = chains_file {
+            let text = std::fs::read_to_string(path)
+                .with_context(|| format!("Failed to read chains file {}", path.display()))?;
+            registry.extend_from_json(&text)
+                .with_context(|| format!("Invalid chains file {}", path.display()))?;
+        }
+        Ok(registry)
+    }
+
+    fn extend_from_json(&mut self, text: &str) -> Result<()> {
+        let chains: Vec<ChainInfo> = serde_json::from_str(text)?;
+        for chain in chains {
+            self.chains.insert(chain.id, chain);
+        }
+        Ok(())
+    }
+
+    /// Details for a chain ID; unknown chains get a generic name and ETH-like defaults
+    pub fn lookup(&self, id: u64) -> ChainInfo {
+        self.chains.get(&id).cloned().unwrap_or_else(|| ChainInfo {
+            id,
+            name: format!("Chain {}", id),
+            ..fallback().clone()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lookup_with_user_chains() {
+        let mut registry = ChainRegistry::builtin();
+        registry
+            .extend_from_json(r#"[
+                {"id": 8453, "name": "Base (internal)", "explorer_tx_url": "https://scan.internal/tx/{hash}"},
+                {"id": 777, "name": "Devnet", "symbol": "DEV", "decimals": 9}
+            ]"#)
+            .unwrap();
+
+        let base = registry.lookup(8453);
+        assert_eq!(base.name, "Base (internal)");
+        assert_eq!(base.symbol, "ETH");
+        assert_eq!(base.explorer_link("0xabc").as_deref(), Some("https://scan.internal/tx/0xabc"));
+
+        let devnet = registry.lookup(777);
+        assert_eq!((devnet.symbol.as_str(), devnet.decimals), ("DEV", 9));
+
+        assert_eq!(registry.lookup(1).explorer_link("0x1").as_deref(), Some("https://etherscan.io/tx/0x1"));
+        assert_eq!(registry.lookup(4242).name, "Chain 4242");
+    }
+}
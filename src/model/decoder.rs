@@ -1,91 +1,63 @@
-use once_cell::sync::Lazy;
-use std::collections::HashMap;
-
-use super::FunctionSignature;
-
-// Common function signatures on Ethereum-compatible chains
-static SIGNATURES: Lazy<HashMap<&'static str, &'static str>> = Lazy::new(|| {
-    let mut m = HashMap::new();
-
-    // ERC20 functions
-    m.insert("0xa9059cbb", "transfer");
-    m.insert("0x23b872dd", "transferFrom");
-    m.insert("0x095ea7b3", "approve");
-    m.insert("0xdd62ed3e", "allowance");
-    m.insert("0x70a08231", "balanceOf");
-    m.insert("0x18160ddd", "totalSupply");
-
-    // Uniswap/DEX functions
-    m.insert("0x38ed1739", "swapExactTokensForTokens");
-    m.insert("0x7ff36ab5", "swapExactETHForTokens");
-    m.insert("0x18cbafe5", "swapExactTokensForETH");
-    m.insert("0x4a25d94a", "swapTokensForExactETH");
-    m.insert("0xfb3bdb41", "swapETHForExactTokens");
-    m.insert("0x5c11d795", "swapExactTokensForTokensSupportingFeeOnTransferTokens");
-    m.insert("0xb6f9de95", "swapExactETHForTokensSupportingFeeOnTransferTokens");
-    m.insert("0x791ac947", "swapExactTokensForETHSupportingFeeOnTransferTokens");
-
-    // Liquidity functions
-    m.insert("0xe8e33700", "addLiquidity");
-    m.insert("0xf305d719", "addLiquidityETH");
-    m.insert("0xbaa2abde", "removeLiquidity");
-    m.insert("0x02751cec", "removeLiquidityETH");
-    m.insert("0xaf2979eb", "removeLiquidityETHSupportingFeeOnTransferTokens");
-    m.insert("0xded9382a", "removeLiquidityETHWithPermit");
-    m.insert("0x2195995c", "removeLiquidityWithPermit");
-
-    // NFT functions
-    m.insert("0x42842e0e", "safeTransferFrom");
-    m.insert("0xb88d4fde", "safeTransferFromWithData");
-    m.insert("0x23b872dd", "transferFrom"); // Same as ERC20
-    m.insert("0x6352211e", "ownerOf");
-    m.insert("0x081812fc", "getApproved");
-    m.insert("0xa22cb465", "setApprovalForAll");
-    m.insert("0xe985e9c5", "isApprovedForAll");
-    m.insert("0x40c10f19", "mint");
-    m.insert("0x42966c68", "burn");
-
-    // WETH functions
-    m.insert("0xd0e30db0", "deposit");
-    m.insert("0x2e1a7d4d", "withdraw");
-
-    // Multicall
-    m.insert("0xac9650d8", "multicall");
-    m.insert("0x5ae401dc", "multicallWithDeadline");
-
-    // Bridge functions
-    m.insert("0x3ceda011", "bridgeETH");
-    m.insert("0xd92d0bd7", "bridgeERC20");
-    m.insert("0x8eb388f3", "bridgeNativeToken");
-
-    // Staking functions
-    m.insert("0xa694fc3a", "stake");
-    m.insert("0x2e17de78", "unstake");
-    m.insert("0x3d18b912", "getReward");
-    m.insert("0xe9fad8ee", "exit");
-    m.insert("0x379607f5", "claim");
-
-    // Governance
-    m.insert("0x15373e3d", "castVote");
-    m.insert("0x56781388", "castVoteWithReason");
-    m.insert("0x7b3c71d3", "castVoteWithReasonAndParams");
-    m.insert("0xc9d27afe", "castVoteBySig");
-    m.insert("0xea0217cf", "propose");
-    m.insert("0x40e58ee5", "cancel");
-    m.insert("0xfe0d94c1", "execute");
-    m.insert("0x2656227d", "queue");
-
-    // Other common functions
-    m.insert("0x3ccfd60b", "withdraw");
-    m.insert("0x1249c58b", "mint");
-    m.insert("0x853828b6", "withdrawAll");
-    m.insert("0x1cff79cd", "execute");
-    m.insert("0x9059cbb2", "transfer");
-
-    m
-});
-
-/// Decode a function signature from transaction data
+use alloy::dyn_abi::{DynSolValue, EventExt, JsonAbiExt};
+use alloy::json_abi::{Function, Param};
+use alloy::primitives::{Address, Selector, B256};
+
+use super::registry::registry;
+use super::{DecodedLog, DecodedParam, FunctionSignature};
+
+/// Decode a call to `to`, preferring the ABI bound to that address in the contracts file.
+/// Anything resolved by selector alone is marked as guessed.
+pub fn decode_call(to: Option<&str>, data: &str) -> Option<FunctionSignature> {
+    if data.len() < 10 {
+        return None;
+    }
+
+    let binding = to
+        .and_then(|addr| addr.parse::<Address>().ok())
+        .and_then(|addr| registry().contract(&addr));
+    let selector = data[0..10].to_lowercase();
+
+    match binding.and_then(|b| b.function(&selector.parse::<Selector>().ok()?)) {
+        Some(function) => Some(decode_bound_call(function, selector, data)),
+        None => decode_function(data),
+    }
+}
+
+/// Decode a call with the function from the ABI bound to its address. Calldata that
+/// doesn't fit that function isn't verified, so it is resolved like any other selector,
+/// keeping the bound name (marked guessed) if nothing else knows it.
+fn decode_bound_call(function: &Function, selector: String, data: &str) -> FunctionSignature {
+    let values = hex::decode(&data[10..])
+        .ok()
+        .and_then(|args| decode_values(function, &args, false));
+
+    match values {
+        Some(values) => FunctionSignature {
+            selector,
+            name: function.name.clone(),
+            signature: function.signature(),
+            args: to_decoded_params(function, &values),
+            alternatives: Vec::new(),
+            guessed: false,
+        },
+        None => decode_function(data).unwrap_or_else(|| FunctionSignature {
+            selector,
+            name: function.name.clone(),
+            signature: function.signature(),
+            args: Vec::new(),
+            alternatives: Vec::new(),
+            guessed: true,
+        }),
+    }
+}
+
+/// Get the label of a contract listed in the contracts file
+pub fn contract_label(address: &str) -> Option<String> {
+    let address = address.parse::<Address>().ok()?;
+    registry().contract(&address).map(|binding| binding.label.clone())
+}
+
+/// Decode a function signature and its arguments from transaction data by selector alone
 pub fn decode_function(data: &str) -> Option<FunctionSignature> {
     // Check if data is long enough to contain a function selector
     if data.len() < 10 {
@@ -93,15 +65,167 @@ pub fn decode_function(data: &str) -> Option<FunctionSignature> {
     }
 
     // Extract the function selector (first 4 bytes = 8 hex chars + 0x)
-    let selector = &data[0..10];
+    let selector = data[0..10].to_lowercase();
+    let candidates = registry().candidates(&selector.parse::<Selector>().ok()?);
+    let args = hex::decode(&data[10..]).ok();
 
-    // Look up the function name
-    SIGNATURES.get(selector).map(|name| FunctionSignature {
-        selector: selector.to_string(),
-        name: name.to_string(),
+    // Prefer a candidate that decodes cleanly, then one that decodes at all,
+    // and finally fall back to naming the first candidate without arguments
+    let decode_with = |strict: bool| {
+        candidates.iter().enumerate().find_map(|(i, function)| {
+            decode_values(function, args.as_deref()?, strict).map(|values| (i, values))
+        })
+    };
+    let (chosen, values) = decode_with(true)
+        .or_else(|| decode_with(false))
+        .or_else(|| (!candidates.is_empty()).then(|| (0, Vec::new())))?;
+
+    let function = &candidates[chosen];
+    let alternatives = candidates
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| *i != chosen)
+        .map(|(_, f)| f.signature())
+        .collect();
+
+    Some(FunctionSignature {
+        selector,
+        name: function.name.clone(),
+        signature: function.signature(),
+        args: to_decoded_params(function, &values),
+        alternatives,
+        guessed: true,
     })
 }
 
+/// Decode the ABI-encoded arguments that follow the selector.
+/// In strict mode the values must also re-encode to exactly the original bytes,
+/// which rejects colliding signatures that only happen to parse.
+fn decode_values(function: &Function, args: &[u8], strict: bool) -> Option<Vec<DynSolValue>> {
+    let values = function.abi_decode_input(args, strict).ok()?;
+    if strict && function.abi_encode_input_raw(&values).ok()? != args {
+        return None;
+    }
+    Some(values)
+}
+
+/// Pair decoded values with the function's parameter names and types
+fn to_decoded_params(function: &Function, values: &[DynSolValue]) -> Vec<DecodedParam> {
+    function
+        .inputs
+        .iter()
+        .zip(values)
+        .enumerate()
+        .map(|(i, (param, value))| to_decoded_param(param_name(&param.name, i), &param.ty, &param.components, value))
+        .collect()
+}
+
+/// Decode a receipt log using the known events for its first topic
+pub fn decode_log(address: &Address, topics: &[B256], data: &[u8]) -> DecodedLog {
+    let mut log = DecodedLog {
+        address: format!("{:#x}", address),
+        name: None,
+        params: Vec::new(),
+        topics: topics.iter().map(|t| format!("{:#x}", t)).collect(),
+        data: format!("0x{}", hex::encode(data)),
+    };
+
+    let Some(topic0) = topics.first() else {
+        return log; // Anonymous event
+    };
+
+    // The first event that accepts the topic count and data layout wins
+    for event in registry().events(topic0) {
+        let Ok(decoded) = event.decode_log_parts(topics.iter().copied(), data, false) else {
+            continue;
+        };
+
+        // Reassemble params in declaration order from the indexed and body values
+        let mut indexed = decoded.indexed.iter();
+        let mut body = decoded.body.iter();
+        log.params = event
+            .inputs
+            .iter()
+            .enumerate()
+            .filter_map(|(i, param)| {
+                let value = if param.indexed { indexed.next() } else { body.next() }?;
+                Some(to_decoded_param(param_name(&param.name, i), &param.ty, &param.components, value))
+            })
+            .collect();
+        log.name = Some(event.name.clone());
+        break;
+    }
+
+    log
+}
+
+/// Convert a decoded ABI value into a named tree node
+fn to_decoded_param(name: String, ty: &str, components: &[Param], value: &DynSolValue) -> DecodedParam {
+    let (value_text, children) = match value {
+        DynSolValue::Array(items) | DynSolValue::FixedArray(items) => {
+            // Element type is the array type without its last dimension
+            let elem_ty = ty.rfind('[').map(|i| &ty[..i]).unwrap_or(ty);
+            let children = items
+                .iter()
+                .enumerate()
+                .map(|(i, item)| to_decoded_param(format!("[{}]", i), elem_ty, components, item))
+                .collect();
+            (String::new(), children)
+        }
+        DynSolValue::Tuple(items) => {
+            let children = items
+                .iter()
+                .enumerate()
+                .map(|(i, item)| match components.get(i) {
+                    Some(component) => to_decoded_param(
+                        param_name(&component.name, i),
+                        &component.ty,
+                        &component.components,
+                        item,
+                    ),
+                    None => {
+                        let item_ty = item.sol_type_name().unwrap_or_default();
+                        to_decoded_param(format!("[{}]", i), &item_ty, &[], item)
+                    }
+                })
+                .collect();
+            (String::new(), children)
+        }
+        leaf => (format_value(leaf), Vec::new()),
+    };
+
+    DecodedParam {
+        name,
+        ty: ty.to_string(),
+        value: value_text,
+        children,
+    }
+}
+
+/// Format a leaf ABI value for display
+fn format_value(value: &DynSolValue) -> String {
+    match value {
+        DynSolValue::Address(addr) => format!("{:#x}", addr),
+        DynSolValue::Uint(n, _) => n.to_string(),
+        DynSolValue::Int(n, _) => n.to_string(),
+        DynSolValue::Bool(b) => b.to_string(),
+        DynSolValue::FixedBytes(word, size) => format!("0x{}", hex::encode(&word[..*size])),
+        DynSolValue::Bytes(bytes) => format!("0x{}", hex::encode(bytes)),
+        DynSolValue::String(s) => format!("{:?}", s),
+        DynSolValue::Function(f) => format!("0x{}", hex::encode(f.as_slice())),
+        _ => String::new(),
+    }
+}
+
+/// Use the declared parameter name, or its position if the ABI leaves it unnamed
+fn param_name(name: &str, index: usize) -> String {
+    if name.is_empty() {
+        format!("[{}]", index)
+    } else {
+        name.to_string()
+    }
+}
+
 /// Get a color for a function based on its type
 pub fn get_function_color(function_name: &str) -> ratatui::style::Color {
     use ratatui::style::Color;
@@ -137,4 +261,120 @@ pub fn get_function_color(function_name: &str) -> ratatui::style::Color {
         // Unknown - gray
         _ => Color::Gray,
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_transfer_arguments() {
+        let data = concat!(
+            "0xa9059cbb",
+            "000000000000000000000000d8da6bf26964af9d7eed9e03e53415d37aa96045",
+            "00000000000000000000000000000000000000000000000000000000000003e8",
+        );
+
+        let sig = decode_function(data).unwrap();
+        assert_eq!(sig.name, "transfer");
+        assert_eq!(sig.signature, "transfer(address,uint256)");
+        assert_eq!(sig.args.len(), 2);
+        assert_eq!(sig.args[0].name, "to");
+        assert_eq!(sig.args[0].ty, "address");
+        assert_eq!(sig.args[0].value, "0xd8da6bf26964af9d7eed9e03e53415d37aa96045");
+        assert_eq!(sig.args[1].name, "amount");
+        assert_eq!(sig.args[1].value, "1000");
+    }
+
+    #[test]
+    fn test_decode_array_arguments() {
+        let data = concat!(
+            "0x7ff36ab5",
+            "0000000000000000000000000000000000000000000000000000000000000001", // amountOutMin
+            "0000000000000000000000000000000000000000000000000000000000000080", // path offset
+            "000000000000000000000000000000000000000000000000000000000000dead", // to
+            "0000000000000000000000000000000000000000000000000000000000000064", // deadline
+            "0000000000000000000000000000000000000000000000000000000000000002", // path length
+            "000000000000000000000000000000000000000000000000000000000000aaaa",
+            "000000000000000000000000000000000000000000000000000000000000bbbb",
+        );
+
+        let sig = decode_function(data).unwrap();
+        let path = &sig.args[1];
+        assert_eq!(path.name, "path");
+        assert_eq!(path.ty, "address[]");
+        assert_eq!(path.children.len(), 2);
+        assert_eq!(path.children[0].name, "[0]");
+        assert_eq!(path.children[0].ty, "address");
+        assert!(path.children[1].value.ends_with("bbbb"));
+    }
+
+    #[test]
+    fn test_bound_call_that_fails_to_decode_is_not_verified() {
+        let function = Function::parse("transfer(address to, uint256 amount)").unwrap();
+        let valid = concat!(
+            "0xa9059cbb",
+            "000000000000000000000000d8da6bf26964af9d7eed9e03e53415d37aa96045",
+            "00000000000000000000000000000000000000000000000000000000000003e8",
+        );
+        let sig = decode_bound_call(&function, "0xa9059cbb".to_string(), valid);
+        assert!(!sig.guessed);
+        assert_eq!(sig.args.len(), 2);
+
+        let sig = decode_bound_call(&function, "0xa9059cbb".to_string(), "0xa9059cbb1234");
+        assert!(sig.guessed);
+        assert_eq!(sig.name, "transfer");
+
+        // Unknown outside the bound ABI: the bound name is kept, still unverified
+        let custom = Function::parse("settle(uint256 id)").unwrap();
+        let selector = format!("0x{}", hex::encode(custom.selector()));
+        let sig = decode_bound_call(&custom, selector.clone(), &format!("{}12", selector));
+        assert_eq!((sig.name.as_str(), sig.guessed), ("settle", true));
+    }
+
+    #[test]
+    fn test_malformed_arguments_keep_name() {
+        let sig = decode_function("0xa9059cbb1234").unwrap();
+        assert_eq!(sig.name, "transfer");
+        assert!(sig.args.is_empty());
+    }
+
+    #[test]
+    fn test_strict_decode_rejects_collisions() {
+        let args = hex::decode(concat!(
+            "000000000000000000000000d8da6bf26964af9d7eed9e03e53415d37aa96045",
+            "00000000000000000000000000000000000000000000000000000000000003e8",
+        ))
+        .unwrap();
+
+        let transfer = Function::parse("transfer(address,uint256)").unwrap();
+        let babbage = Function::parse("many_msg_babbage(bytes1)").unwrap();
+
+        assert!(decode_values(&transfer, &args, true).is_some());
+        assert!(decode_values(&babbage, &args, true).is_none());
+    }
+
+    #[test]
+    fn test_decode_transfer_log() {
+        let token = Address::with_last_byte(0x42);
+        let topics = [
+            "0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef".parse().unwrap(),
+            B256::left_padding_from(&[0xaa]),
+            B256::left_padding_from(&[0xbb]),
+        ];
+        let data = B256::left_padding_from(&[0x03, 0xe8]);
+
+        let log = decode_log(&token, &topics, data.as_slice());
+        assert_eq!(log.name.as_deref(), Some("Transfer"));
+        assert_eq!(log.params.len(), 3);
+        assert_eq!(log.params[0].name, "from");
+        assert!(log.params[1].value.ends_with("bb"));
+        assert_eq!(log.params[2].name, "value");
+        assert_eq!(log.params[2].value, "1000");
+
+        // Unknown events keep their raw topics
+        let unknown = decode_log(&token, &[B256::ZERO], &[]);
+        assert!(unknown.name.is_none());
+        assert_eq!(unknown.topics.len(), 1);
+    }
+}
@@ -1,4 +1,9 @@
+pub mod chains;
 pub mod decoder;
+pub mod registry;
+pub mod sigdb;
 pub mod transaction;
+pub mod units;
 
-pub use transaction::{FunctionSignature, Transaction};
\ No newline at end of file
+pub use chains::ChainInfo;
+pub use transaction::{AccessListEntry, DecodedLog, DecodedParam, FunctionSignature, Lifecycle, Sighting, Transaction, TxSignature};
\ No newline at end of file
@@ -0,0 +1,448 @@
+use alloy::json_abi::{Error, Event, Function, JsonAbi};
+use alloy::primitives::{Address, Selector, B256};
+use anyhow::{Context, Result};
+use once_cell::sync::OnceCell;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use super::sigdb::SignatureDb;
+
+// Common function signatures on Ethereum-compatible chains.
+// Selectors are derived from the signatures, so every entry is guaranteed to match.
+const BUILTIN_SIGNATURES: &[&str] = &[
+    // ERC20 functions
+    "transfer(address to, uint256 amount)",
+    "transferFrom(address from, address to, uint256 amount)",
+    "approve(address spender, uint256 amount)",
+    "allowance(address owner, address spender)",
+    "balanceOf(address account)",
+    "totalSupply()",
+
+    // Uniswap/DEX functions
+    "swapExactTokensForTokens(uint256 amountIn, uint256 amountOutMin, address[] path, address to, uint256 deadline)",
+    "swapExactETHForTokens(uint256 amountOutMin, address[] path, address to, uint256 deadline)",
+    "swapExactTokensForETH(uint256 amountIn, uint256 amountOutMin, address[] path, address to, uint256 deadline)",
+    "swapTokensForExactETH(uint256 amountOut, uint256 amountInMax, address[] path, address to, uint256 deadline)",
+    "swapETHForExactTokens(uint256 amountOut, address[] path, address to, uint256 deadline)",
+    "swapExactTokensForTokensSupportingFeeOnTransferTokens(uint256 amountIn, uint256 amountOutMin, address[] path, address to, uint256 deadline)",
+    "swapExactETHForTokensSupportingFeeOnTransferTokens(uint256 amountOutMin, address[] path, address to, uint256 deadline)",
+    "swapExactTokensForETHSupportingFeeOnTransferTokens(uint256 amountIn, uint256 amountOutMin, address[] path, address to, uint256 deadline)",
+
+    // Liquidity functions
+    "addLiquidity(address tokenA, address tokenB, uint256 amountADesired, uint256 amountBDesired, uint256 amountAMin, uint256 amountBMin, address to, uint256 deadline)",
+    "addLiquidityETH(address token, uint256 amountTokenDesired, uint256 amountTokenMin, uint256 amountETHMin, address to, uint256 deadline)",
+    "removeLiquidity(address tokenA, address tokenB, uint256 liquidity, uint256 amountAMin, uint256 amountBMin, address to, uint256 deadline)",
+    "removeLiquidityETH(address token, uint256 liquidity, uint256 amountTokenMin, uint256 amountETHMin, address to, uint256 deadline)",
+    "removeLiquidityETHSupportingFeeOnTransferTokens(address token, uint256 liquidity, uint256 amountTokenMin, uint256 amountETHMin, address to, uint256 deadline)",
+    "removeLiquidityETHWithPermit(address token, uint256 liquidity, uint256 amountTokenMin, uint256 amountETHMin, address to, uint256 deadline, bool approveMax, uint8 v, bytes32 r, bytes32 s)",
+    "removeLiquidityWithPermit(address tokenA, address tokenB, uint256 liquidity, uint256 amountAMin, uint256 amountBMin, address to, uint256 deadline, bool approveMax, uint8 v, bytes32 r, bytes32 s)",
+
+    // NFT functions (transferFrom is shared with ERC20)
+    "safeTransferFrom(address from, address to, uint256 tokenId)",
+    "safeTransferFrom(address from, address to, uint256 tokenId, bytes data)",
+    "ownerOf(uint256 tokenId)",
+    "getApproved(uint256 tokenId)",
+    "setApprovalForAll(address operator, bool approved)",
+    "isApprovedForAll(address owner, address operator)",
+    "mint(address to, uint256 amount)",
+    "burn(uint256 amount)",
+
+    // WETH functions
+    "deposit()",
+    "withdraw(uint256 amount)",
+
+    // Multicall
+    "multicall(bytes[] data)",
+    "multicall(uint256 deadline, bytes[] data)",
+
+    // Bridge functions
+    "bridgeETH(uint32 minGasLimit, bytes extraData)",
+    "bridgeETHTo(address to, uint32 minGasLimit, bytes extraData)",
+    "bridgeERC20(address localToken, address remoteToken, uint256 amount, uint32 minGasLimit, bytes extraData)",
+
+    // Staking functions
+    "stake(uint256 amount)",
+    "unstake(uint256 amount)",
+    "getReward()",
+    "exit()",
+    "claim(uint256 amount)",
+
+    // Governance
+    "castVote(uint256 proposalId, uint8 support)",
+    "castVote(uint256 proposalId, bool support)",
+    "castVoteWithReason(uint256 proposalId, uint8 support, string reason)",
+    "castVoteWithReasonAndParams(uint256 proposalId, uint8 support, string reason, bytes params)",
+    "castVoteBySig(uint256 proposalId, uint8 support, uint8 v, bytes32 r, bytes32 s)",
+    "propose(address[] targets, uint256[] values, bytes[] calldatas, string description)",
+    "propose(address[] targets, uint256[] values, string[] signatures, bytes[] calldatas, string description)",
+    "cancel(uint256 proposalId)",
+    "execute(uint256 proposalId)",
+    "queue(uint256 proposalId)",
+
+    // Other common functions
+    "withdraw()",
+    "mint()",
+    "withdrawAll()",
+    "execute(address target, bytes data)",
+];
+
+// Common events decoded from receipt logs
+const BUILTIN_EVENTS: &[&str] = &[
+    // ERC20 / ERC721 (same topic, told apart by the number of indexed params)
+    "Transfer(address indexed from, address indexed to, uint256 value)",
+    "Transfer(address indexed from, address indexed to, uint256 indexed tokenId)",
+    "Approval(address indexed owner, address indexed spender, uint256 value)",
+    "Approval(address indexed owner, address indexed approved, uint256 indexed tokenId)",
+    "ApprovalForAll(address indexed owner, address indexed operator, bool approved)",
+
+    // WETH
+    "Deposit(address indexed dst, uint256 wad)",
+    "Withdrawal(address indexed src, uint256 wad)",
+
+    // Uniswap V2 / V3
+    "Swap(address indexed sender, uint256 amount0In, uint256 amount1In, uint256 amount0Out, uint256 amount1Out, address indexed to)",
+    "Swap(address indexed sender, address indexed recipient, int256 amount0, int256 amount1, uint160 sqrtPriceX96, uint128 liquidity, int24 tick)",
+    "Sync(uint112 reserve0, uint112 reserve1)",
+];
+
+static REGISTRY: OnceCell<SignatureRegistry> = OnceCell::new();
+
+/// Install the registry used by the decoder. Must be called before the first decode.
+pub fn init(registry: SignatureRegistry) {
+    let _ = REGISTRY.set(registry);
+}
+
+/// Get the active registry, falling back to the built-in table if none was installed
+pub fn registry() -> &'static SignatureRegistry {
+    REGISTRY.get_or_init(SignatureRegistry::builtin)
+}
+
+/// Lookup table for function, event and error signatures.
+///
+/// Entries are kept per selector in insertion order, so signatures loaded from
+/// user ABIs take precedence over the built-in table and real selector
+/// collisions are preserved instead of overwriting each other. The optional
+/// offline signature database is consulted last.
+///
+/// Contracts listed in the contracts file are additionally bound to their own
+/// ABI, which is authoritative for calls to that address.
+#[derive(Default)]
+pub struct SignatureRegistry {
+    functions: HashMap<Selector, Vec<Function>>,
+    events: HashMap<B256, Vec<Event>>,
+    errors: HashMap<Selector, Vec<Error>>,
+    database: Option<SignatureDb>,
+    contracts: HashMap<Address, ContractBinding>,
+}
+
+/// A known contract: display label plus the functions of its bound ABI
+#[derive(Debug, Default)]
+pub struct ContractBinding {
+    pub label: String,
+    functions: HashMap<Selector, Function>,
+}
+
+impl ContractBinding {
+    /// Function of the bound ABI for a selector
+    pub fn function(&self, selector: &Selector) -> Option<&Function> {
+        self.functions.get(selector)
+    }
+}
+
+/// Entry of the contracts file: `{ "0xAddress": { "label": "...", "abi": "path.json" } }`
+#[derive(Debug, Deserialize)]
+struct ContractEntry {
+    label: String,
+    #[serde(default)]
+    abi: Option<PathBuf>,
+}
+
+/// Summary of an ABI directory load
+#[derive(Debug, Clone, Default)]
+pub struct AbiLoadReport {
+    pub files: usize,
+    pub skipped: usize,
+    pub functions: usize,
+    pub events: usize,
+    pub errors: usize,
+    pub db_signatures: usize,
+    pub contracts: usize,
+}
+
+impl SignatureRegistry {
+    /// Registry containing only the built-in signature table
+    pub fn builtin() -> Self {
+        let mut registry = Self::default();
+        registry.add_builtin();
+        registry
+    }
+
+    /// Build the registry for the given config: user ABIs and contract-bound ABIs
+    /// first, built-ins and then the offline signature database as fallbacks
+    pub fn load(
+        abi_dir: Option<&Path>,
+        signature_db: Option<&Path>,
+        contracts_file: Option<&Path>,
+    ) -> Result<(Self, AbiLoadReport)> {
+        let mut registry = Self::default();
+        let mut report = AbiLoadReport::default();
+
+        if let Some(dir) = abi_dir {
+            registry.load_dir(dir, &mut report)?;
+        }
+        if let Some(path) = contracts_file {
+            registry.load_contracts(path, &mut report)?;
+        }
+        registry.add_builtin();
+
+        if let Some(path) = signature_db {
+            let database = SignatureDb::open(path)?;
+            report.db_signatures = database.len();
+            registry.database = Some(database);
+        }
+
+        Ok((registry, report))
+    }
+
+    fn add_builtin(&mut self) {
+        for sig in BUILTIN_SIGNATURES {
+            if let Ok(function) = Function::parse(sig) {
+                self.add_function(function);
+            }
+        }
+        for sig in BUILTIN_EVENTS {
+            if let Ok(event) = Event::parse(sig) {
+                self.add_event(event);
+            }
+        }
+    }
+
+    /// Recursively load every ABI JSON file (plain ABI arrays or Foundry/Hardhat artifacts)
+    fn load_dir(&mut self, dir: &Path, report: &mut AbiLoadReport) -> Result<()> {
+        let entries = std::fs::read_dir(dir)
+            .with_context(|| format!("Failed to read ABI directory {}", dir.display()))?;
+
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.is_dir() {
+                self.load_dir(&path, report)?;
+            } else if path.extension().is_some_and(|ext| ext == "json") {
+                match read_abi_file(&path) {
+                    Some(abi) => {
+                        let (functions, events, errors) = self.add_abi(abi);
+                        report.files += 1;
+                        report.functions += functions;
+                        report.events += events;
+                        report.errors += errors;
+                    }
+                    None => report.skipped += 1,
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Load the address -> label/ABI mapping. ABI paths are relative to the contracts file.
+    fn load_contracts(&mut self, path: &Path, report: &mut AbiLoadReport) -> Result<()> {
+        let contents = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read contracts file {}", path.display()))?;
+        let entries: HashMap<String, ContractEntry> = serde_json::from_str(&contents)
+            .with_context(|| format!("Invalid contracts file {}", path.display()))?;
+        let base_dir = path.parent().unwrap_or(Path::new("."));
+
+        for (address, entry) in entries {
+            let address: Address = address
+                .parse()
+                .with_context(|| format!("Invalid contract address {}", address))?;
+
+            let mut binding = ContractBinding {
+                label: entry.label,
+                functions: HashMap::new(),
+            };
+            if let Some(abi_path) = entry.abi {
+                let abi_path = base_dir.join(abi_path);
+                let abi = read_abi_file(&abi_path)
+                    .with_context(|| format!("Failed to load ABI {}", abi_path.display()))?;
+                binding.functions = abi.functions().map(|f| (f.selector(), f.clone())).collect();
+
+                // Bound ABIs also help decode calls to other addresses, as guesses
+                let (functions, events, errors) = self.add_abi(abi);
+                report.functions += functions;
+                report.events += events;
+                report.errors += errors;
+            }
+
+            self.contracts.insert(address, binding);
+            report.contracts += 1;
+        }
+        Ok(())
+    }
+
+    /// Merge every item of an ABI, returning how many new functions, events and errors were added
+    pub fn add_abi(&mut self, abi: JsonAbi) -> (usize, usize, usize) {
+        let functions = abi.functions().filter(|f| self.add_function((*f).clone())).count();
+        let events = abi.events().filter(|e| self.add_event((*e).clone())).count();
+        let errors = abi.errors().filter(|e| self.add_error((*e).clone())).count();
+        (functions, events, errors)
+    }
+
+    /// Add a function unless the exact same signature is already known
+    pub fn add_function(&mut self, function: Function) -> bool {
+        let entries = self.functions.entry(function.selector()).or_default();
+        let signature = function.signature();
+        if entries.iter().any(|f| f.signature() == signature) {
+            return false;
+        }
+        entries.push(function);
+        true
+    }
+
+    /// Add an event unless the same signature with the same indexed params is already known
+    pub fn add_event(&mut self, event: Event) -> bool {
+        let entries = self.events.entry(event.selector()).or_default();
+        let indexed = |e: &Event| e.inputs.iter().map(|p| p.indexed).collect::<Vec<_>>();
+        let signature = event.signature();
+        if entries.iter().any(|e| e.signature() == signature && indexed(e) == indexed(&event)) {
+            return false;
+        }
+        entries.push(event);
+        true
+    }
+
+    /// Add a custom error unless the exact same signature is already known
+    pub fn add_error(&mut self, error: Error) -> bool {
+        let entries = self.errors.entry(error.selector()).or_default();
+        let signature = error.signature();
+        if entries.iter().any(|e| e.signature() == signature) {
+            return false;
+        }
+        entries.push(error);
+        true
+    }
+
+    /// All known functions for a selector, highest priority first
+    pub fn functions(&self, selector: &Selector) -> &[Function] {
+        self.functions.get(selector).map(Vec::as_slice).unwrap_or_default()
+    }
+
+    /// All known events for a log's first topic, highest priority first
+    pub fn events(&self, topic0: &B256) -> &[Event] {
+        self.events.get(topic0).map(Vec::as_slice).unwrap_or_default()
+    }
+
+    /// The binding for a contract address, if it is listed in the contracts file
+    pub fn contract(&self, address: &Address) -> Option<&ContractBinding> {
+        self.contracts.get(address)
+    }
+
+    /// Every candidate for a selector: registered functions followed by any
+    /// additional signatures from the offline database
+    pub fn candidates(&self, selector: &Selector) -> Vec<Function> {
+        let mut candidates = self.functions(selector).to_vec();
+
+        if let Some(database) = &self.database {
+            for function in database.lookup(selector) {
+                let signature = function.signature();
+                if !candidates.iter().any(|f| f.signature() == signature) {
+                    candidates.push(function);
+                }
+            }
+        }
+        candidates
+    }
+}
+
+/// Read an ABI from a JSON file; returns `None` for JSON that isn't an ABI (e.g. build-info files)
+fn read_abi_file(path: &Path) -> Option<JsonAbi> {
+    let contents = std::fs::read_to_string(path).ok()?;
+    let json: serde_json::Value = serde_json::from_str(&contents).ok()?;
+
+    // Foundry and Hardhat artifacts wrap the ABI in an "abi" field
+    let abi = match json {
+        serde_json::Value::Object(mut artifact) => artifact.remove("abi")?,
+        array @ serde_json::Value::Array(_) => array,
+        _ => return None,
+    };
+    serde_json::from_value(abi).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builtin_selectors() {
+        let registry = SignatureRegistry::builtin();
+        let transfer = registry.functions(&Selector::from([0xa9, 0x05, 0x9c, 0xbb]));
+        assert_eq!(transfer.len(), 1);
+        assert_eq!(transfer[0].signature(), "transfer(address,uint256)");
+
+        let total: usize = registry.functions.values().map(Vec::len).sum();
+        assert_eq!(total, BUILTIN_SIGNATURES.len());
+    }
+
+    #[test]
+    fn test_loaded_abi_takes_precedence() {
+        let dir = std::env::temp_dir().join(format!("abi-registry-test-{}", std::process::id()));
+        std::fs::create_dir_all(dir.join("Token.sol")).unwrap();
+        std::fs::write(
+            dir.join("Token.sol/Token.json"),
+            r#"{"abi": [
+                {"type": "function", "name": "transfer", "inputs": [
+                    {"name": "recipient", "type": "address"},
+                    {"name": "value", "type": "uint256"}
+                ], "outputs": [], "stateMutability": "nonpayable"},
+                {"type": "event", "name": "Paused", "inputs": [], "anonymous": false},
+                {"type": "error", "name": "NotOwner", "inputs": []}
+            ]}"#,
+        )
+        .unwrap();
+        std::fs::write(dir.join("build-info.json"), r#"{"id": "abc"}"#).unwrap();
+
+        let (registry, report) = SignatureRegistry::load(Some(&dir), None, None).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(report.files, 1);
+        assert_eq!(report.skipped, 1);
+        assert_eq!((report.functions, report.events, report.errors), (1, 1, 1));
+
+        // The loaded transfer wins; the identical built-in signature is not duplicated
+        let transfer = registry.functions(&Selector::from([0xa9, 0x05, 0x9c, 0xbb]));
+        assert_eq!(transfer.len(), 1);
+        assert_eq!(transfer[0].inputs[0].name, "recipient");
+    }
+
+    #[test]
+    fn test_contract_bindings() {
+        let dir = std::env::temp_dir().join(format!("contracts-registry-test-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(
+            dir.join("Vault.json"),
+            r#"[{"type": "function", "name": "sweep", "inputs": [{"name": "to", "type": "address"}],
+                 "outputs": [], "stateMutability": "nonpayable"}]"#,
+        )
+        .unwrap();
+        std::fs::write(
+            dir.join("contracts.json"),
+            r#"{
+                "0x00000000000000000000000000000000000000AA": {"label": "Vault", "abi": "Vault.json"},
+                "0x00000000000000000000000000000000000000bb": {"label": "Treasury"}
+            }"#,
+        )
+        .unwrap();
+
+        let (registry, report) = SignatureRegistry::load(None, None, Some(&dir.join("contracts.json"))).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(report.contracts, 2);
+        let vault = registry.contract(&Address::with_last_byte(0xaa)).unwrap();
+        assert_eq!(vault.label, "Vault");
+        let sweep = Function::parse("sweep(address)").unwrap();
+        assert!(vault.function(&sweep.selector()).is_some());
+
+        // Bound functions are also available for selector-only lookups
+        assert_eq!(registry.functions(&sweep.selector()).len(), 1);
+        assert!(registry.contract(&Address::with_last_byte(0xbb)).is_some());
+    }
+}
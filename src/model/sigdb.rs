@@ -0,0 +1,181 @@
+use alloy::json_abi::Function;
+use alloy::primitives::Selector;
+use anyhow::{bail, Context, Result};
+use std::path::{Path, PathBuf};
+
+const INDEX_MAGIC: &[u8; 8] = b"W3TXSIG1";
+const HEADER_LEN: usize = INDEX_MAGIC.len() + 4;
+const RECORD_LEN: usize = 12; // selector (4) + offset (4) + length (4)
+
+/// Offline selector -> signature database built from a `selector,signature` dump.
+///
+/// The text dump is compiled once into a compact binary index stored next to it
+/// (`<file>.idx`): a table of fixed-size records sorted by selector followed by a
+/// blob of signature strings. Lookups binary-search the record table, so only the
+/// signatures for the requested selector are ever parsed.
+pub struct SignatureDb {
+    index: Vec<u8>,
+    count: usize,
+}
+
+impl SignatureDb {
+    /// Open a signature dump, reusing its on-disk index when it is up to date
+    pub fn open(path: &Path) -> Result<Self> {
+        let index_path = index_path(path);
+
+        if index_is_fresh(path, &index_path) {
+            if let Ok(db) = std::fs::read(&index_path).map_err(anyhow::Error::from).and_then(Self::from_index) {
+                return Ok(db);
+            }
+        }
+
+        let text = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read signature database {}", path.display()))?;
+        let index = build_index(&text);
+
+        // Persisting the index is an optimisation only; a read-only location is fine
+        let _ = std::fs::write(&index_path, &index);
+
+        Self::from_index(index)
+    }
+
+    fn from_index(index: Vec<u8>) -> Result<Self> {
+        if index.len() < HEADER_LEN || &index[..INDEX_MAGIC.len()] != INDEX_MAGIC {
+            bail!("Invalid signature index");
+        }
+        let count = read_u32(&index, INDEX_MAGIC.len()) as usize;
+        if index.len() < HEADER_LEN + count * RECORD_LEN {
+            bail!("Truncated signature index");
+        }
+        Ok(Self { index, count })
+    }
+
+    /// Number of signatures in the database
+    pub fn len(&self) -> usize {
+        self.count
+    }
+
+    /// All signatures recorded for a selector, in dump order
+    pub fn lookup(&self, selector: &Selector) -> Vec<Function> {
+        // Binary search for the first record with this selector
+        let (mut lo, mut hi) = (0, self.count);
+        while lo < hi {
+            let mid = (lo + hi) / 2;
+            if self.record_selector(mid) < selector.as_slice() {
+                lo = mid + 1;
+            } else {
+                hi = mid;
+            }
+        }
+
+        (lo..self.count)
+            .take_while(|&i| self.record_selector(i) == selector.as_slice())
+            .filter_map(|i| Function::parse(self.record_signature(i)?).ok())
+            .collect()
+    }
+
+    fn record_selector(&self, i: usize) -> &[u8] {
+        let start = HEADER_LEN + i * RECORD_LEN;
+        &self.index[start..start + 4]
+    }
+
+    fn record_signature(&self, i: usize) -> Option<&str> {
+        let start = HEADER_LEN + i * RECORD_LEN;
+        let blob_start = HEADER_LEN + self.count * RECORD_LEN;
+        let offset = blob_start + read_u32(&self.index, start + 4) as usize;
+        let len = read_u32(&self.index, start + 8) as usize;
+        std::str::from_utf8(self.index.get(offset..offset + len)?).ok()
+    }
+}
+
+fn index_path(path: &Path) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(".idx");
+    PathBuf::from(name)
+}
+
+/// The index is reusable if it is at least as new as the dump it was built from
+fn index_is_fresh(path: &Path, index_path: &Path) -> bool {
+    let modified = |p: &Path| std::fs::metadata(p).and_then(|m| m.modified()).ok();
+    matches!((modified(path), modified(index_path)), (Some(src), Some(idx)) if idx >= src)
+}
+
+/// Parse a dump line: `0xa9059cbb,transfer(address,uint256)` (comma, tab or space separated)
+fn parse_line(line: &str) -> Option<([u8; 4], &str)> {
+    let line = line.trim();
+    if line.is_empty() || line.starts_with('#') {
+        return None;
+    }
+
+    let split = line.find([',', '\t', ' '])?;
+    let selector_hex = line[..split].trim();
+    let signature = line[split + 1..].trim();
+
+    let mut selector = [0u8; 4];
+    hex::decode_to_slice(selector_hex.strip_prefix("0x").unwrap_or(selector_hex), &mut selector).ok()?;
+
+    // Skip entries that aren't valid signatures (headers, truncated dumps, ...)
+    Function::parse(signature).ok()?;
+    Some((selector, signature))
+}
+
+/// Compile a text dump into the binary index format
+fn build_index(text: &str) -> Vec<u8> {
+    let mut entries: Vec<([u8; 4], &str)> = text.lines().filter_map(parse_line).collect();
+    // Stable sort keeps dump order among colliding selectors
+    entries.sort_by_key(|(selector, _)| *selector);
+    entries.dedup();
+
+    let blob_len: usize = entries.iter().map(|(_, sig)| sig.len()).sum();
+    let mut index = Vec::with_capacity(HEADER_LEN + entries.len() * RECORD_LEN + blob_len);
+    index.extend_from_slice(INDEX_MAGIC);
+    index.extend_from_slice(&(entries.len() as u32).to_le_bytes());
+
+    let mut offset = 0u32;
+    for (selector, signature) in &entries {
+        index.extend_from_slice(selector);
+        index.extend_from_slice(&offset.to_le_bytes());
+        index.extend_from_slice(&(signature.len() as u32).to_le_bytes());
+        offset += signature.len() as u32;
+    }
+    for (_, signature) in &entries {
+        index.extend_from_slice(signature.as_bytes());
+    }
+
+    index
+}
+
+fn read_u32(bytes: &[u8], at: usize) -> u32 {
+    u32::from_le_bytes([bytes[at], bytes[at + 1], bytes[at + 2], bytes[at + 3]])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_index_lookup_with_collisions() {
+        let text = "\
+selector,signature
+0xa9059cbb,transfer(address,uint256)
+0x095ea7b3,approve(address,uint256)
+0xa9059cbb,many_msg_babbage(bytes1)
+not a line
+0x12345678	foo(uint8)
+";
+        let db = SignatureDb::from_index(build_index(text)).unwrap();
+        assert_eq!(db.len(), 4);
+
+        let transfer = db.lookup(&Selector::from([0xa9, 0x05, 0x9c, 0xbb]));
+        let names: Vec<_> = transfer.iter().map(|f| f.name.as_str()).collect();
+        assert_eq!(names, ["transfer", "many_msg_babbage"]);
+
+        assert_eq!(db.lookup(&Selector::from([0x12, 0x34, 0x56, 0x78]))[0].name, "foo");
+        assert!(db.lookup(&Selector::from([0xde, 0xad, 0xbe, 0xef])).is_empty());
+    }
+
+    #[test]
+    fn test_rejects_invalid_index() {
+        assert!(SignatureDb::from_index(b"garbage".to_vec()).is_err());
+    }
+}
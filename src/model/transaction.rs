@@ -1,28 +1,147 @@
+use alloy::primitives::U256;
 use serde::{Deserialize, Serialize};
 use std::borrow::Cow;
+use std::sync::Arc;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Transaction {
     pub hash: String,
     pub from: String,
+    #[serde(default)]
+    pub nonce: u64,
     pub to: Option<String>,
-    pub value: String, // ETH value
-    pub gas_limit: String,
-    pub gas_price: Option<String>,
+    #[serde(with = "amount_serde::ether")]
+    pub value: U256, // Wei; serialized as a decimal ether string
+    #[serde(with = "amount_serde::quantity")]
+    pub gas_limit: u128,
+    #[serde(with = "amount_serde::opt_quantity")]
+    pub gas_price: Option<u128>, // Wei
+    #[serde(default)]
+    pub tx_type: u8, // EIP-2718 type: 0 legacy, 1 access list, 2 EIP-1559, 3 blob
+    #[serde(default, with = "amount_serde::opt_quantity")]
+    pub max_fee_per_gas: Option<u128>, // Wei, EIP-1559 and later
+    #[serde(default, with = "amount_serde::opt_quantity")]
+    pub max_priority_fee_per_gas: Option<u128>, // Wei, EIP-1559 and later
+    #[serde(default, with = "amount_serde::opt_quantity")]
+    pub max_fee_per_blob_gas: Option<u128>, // Wei, EIP-4844 only
+    #[serde(default)]
+    pub access_list: Vec<AccessListEntry>,
+    #[serde(default)]
+    pub blob_versioned_hashes: Vec<String>,
+    #[serde(default)]
+    pub chain_id: Option<u64>, // None for legacy transactions without replay protection
+    #[serde(default)]
+    pub signature: Option<TxSignature>,
     pub data: String,
     pub function_sig: Option<FunctionSignature>,
+    #[serde(default)]
+    pub to_label: Option<String>, // Label of `to` from the contracts file
     pub timestamp: i64,
+    #[serde(default)]
+    pub first_seen_ms: i64, // Unix milliseconds when this client first received it
+    #[serde(default)]
+    pub included_ms: Option<i64>, // Unix milliseconds of the block that included it, once tracked
+    #[serde(default)]
+    pub replaces: Option<String>, // Earlier pending transaction with the same sender and nonce
+    #[serde(default)]
+    pub fee_bump_pct: Option<f64>, // Fee increase over the transaction it replaces
+    #[serde(default)]
+    pub replaced_by: Option<String>,
+    #[serde(default)]
+    pub dropped: bool, // Left pending past the drop timeout
     // Receipt data (populated when fetching by hash or viewing details)
     pub block_number: Option<u64>,
     pub status: Option<bool>, // true = success, false = failed
-    pub gas_used: Option<String>,
-    pub effective_gas_price: Option<String>,
+    #[serde(with = "amount_serde::opt_quantity")]
+    pub gas_used: Option<u128>,
+    #[serde(with = "amount_serde::opt_quantity")]
+    pub effective_gas_price: Option<u128>, // Wei
+    #[serde(default)]
+    pub logs: Vec<DecodedLog>,
+    #[serde(default)]
+    pub seen_by: Vec<Sighting>, // Race mode: endpoints that delivered this transaction, earliest first
+    #[serde(skip)]
+    pub raw: Option<Arc<alloy::rpc::types::Transaction>>, // The JSON-RPC transaction object as received
+}
+
+/// Where a transaction is in its life, as far as this client has seen
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Lifecycle {
+    Pending,
+    Mined,
+    Finalized,
+    Failed, // Mined but reverted
+    Replaced, // Superseded by a transaction with the same sender and nonce
+    Dropped,
+}
+
+impl Lifecycle {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Pending => "pending",
+            Self::Mined => "mined",
+            Self::Finalized => "final",
+            Self::Failed => "failed",
+            Self::Replaced => "replaced",
+            Self::Dropped => "dropped",
+        }
+    }
+}
+
+/// ECDSA signature of a transaction
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TxSignature {
+    pub v: u64,
+    pub r: String,
+    pub s: String,
+    pub y_parity: Option<bool>, // Typed transactions only
+}
+
+/// Address and storage slots pre-declared by an EIP-2930 access list
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccessListEntry {
+    pub address: String,
+    pub storage_keys: Vec<String>,
+}
+
+/// Arrival of a transaction from one endpoint in race mode
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Sighting {
+    pub endpoint: usize, // Index of the endpoint in `RPC_URLS`
+    pub received_ms: i64, // Unix time in milliseconds
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FunctionSignature {
     pub selector: String,
     pub name: String,
+    #[serde(default)]
+    pub signature: String, // Canonical signature, e.g. "transfer(address,uint256)"
+    #[serde(default)]
+    pub args: Vec<DecodedParam>,
+    #[serde(default)]
+    pub alternatives: Vec<String>, // Other signatures sharing this selector
+    #[serde(default)]
+    pub guessed: bool, // Matched by selector only, not by the ABI bound to `to`
+}
+
+/// A receipt log, decoded when its event signature is known
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DecodedLog {
+    pub address: String,
+    pub name: Option<String>, // Event name, None if the event is unknown
+    pub params: Vec<DecodedParam>,
+    pub topics: Vec<String>,
+    pub data: String,
+}
+
+/// A decoded ABI argument; arrays and tuples carry their elements as children
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DecodedParam {
+    pub name: String,
+    pub ty: String,
+    pub value: String, // Empty for arrays and tuples
+    pub children: Vec<DecodedParam>,
 }
 
 impl Transaction {
@@ -63,6 +182,94 @@ impl Transaction {
             .unwrap_or("Unknown")
     }
 
+    /// Check if the function was matched by selector only rather than a bound ABI
+    #[inline]
+    pub fn is_function_guessed(&self) -> bool {
+        self.function_sig.as_ref().is_some_and(|sig| sig.guessed)
+    }
+
+    /// Record another endpoint's arrival, keeping the earliest first.
+    /// Returns false if that endpoint was already recorded.
+    pub fn add_sighting(&mut self, sighting: Sighting) -> bool {
+        if self.seen_by.iter().any(|s| s.endpoint == sighting.endpoint) {
+            return false;
+        }
+        let position = self.seen_by.partition_point(|s| s.received_ms <= sighting.received_ms);
+        self.seen_by.insert(position, sighting);
+        true
+    }
+
+    /// Milliseconds between the first arrival and this one
+    pub fn arrival_delta_ms(&self, sighting: &Sighting) -> i64 {
+        self.seen_by
+            .first()
+            .map_or(0, |first| sighting.received_ms - first.received_ms)
+    }
+
+    /// Short name of the transaction type for the list
+    pub fn type_name(&self) -> Cow<'static, str> {
+        match self.tx_type {
+            0 => Cow::Borrowed("Legacy"),
+            1 => Cow::Borrowed("2930"),
+            2 => Cow::Borrowed("1559"),
+            3 => Cow::Borrowed("4844"),
+            4 => Cow::Borrowed("7702"),
+            other => Cow::Owned(format!("0x{:x}", other)),
+        }
+    }
+
+    /// Full description of the transaction type for the details view
+    pub fn type_description(&self) -> String {
+        let standard = match self.tx_type {
+            0 => "Legacy",
+            1 => "EIP-2930 access list",
+            2 => "EIP-1559 dynamic fee",
+            3 => "EIP-4844 blob",
+            4 => "EIP-7702 set code",
+            0x7e => "OP Stack deposit",
+            _ => "Unknown",
+        };
+        format!("{} ({})", self.tx_type, standard)
+    }
+
+    /// Current stage, given the latest finalized block if the node reports one
+    pub fn lifecycle(&self, finalized_block: Option<u64>) -> Lifecycle {
+        match (self.status, self.block_number) {
+            (Some(false), _) => Lifecycle::Failed,
+            (_, Some(block)) if finalized_block.is_some_and(|finalized| block <= finalized) => Lifecycle::Finalized,
+            (_, Some(_)) => Lifecycle::Mined,
+            (_, None) if self.replaced_by.is_some() => Lifecycle::Replaced,
+            (_, None) if self.dropped => Lifecycle::Dropped,
+            (_, None) => Lifecycle::Pending,
+        }
+    }
+
+    /// Highest price per gas the sender offers: the fee cap, or the gas price for legacy transactions
+    pub fn max_price_per_gas(&self) -> Option<u128> {
+        self.max_fee_per_gas.or(self.gas_price)
+    }
+
+    /// Percentage by which this transaction's fee cap exceeds `replaced`'s
+    pub fn fee_bump_over(&self, replaced: &Transaction) -> Option<f64> {
+        let old = replaced.max_price_per_gas().filter(|&price| price > 0)?;
+        let new = self.max_price_per_gas()?;
+        Some((new as f64 - old as f64) * 100.0 / old as f64)
+    }
+
+    /// Time from first seen in the mempool to the including block's timestamp.
+    /// Block timestamps have second resolution, so this is clamped at zero.
+    pub fn inclusion_latency_ms(&self) -> Option<i64> {
+        self.included_ms
+            .map(|included| (included - self.first_seen_ms).max(0))
+    }
+
+    /// Fee actually paid in wei, known once the receipt is in
+    pub fn fee_wei(&self) -> Option<U256> {
+        let gas_used = self.gas_used?;
+        let price = self.effective_gas_price?;
+        Some(U256::from(gas_used) * U256::from(price))
+    }
+
     /// Format the timestamp as a human-readable string
     pub fn formatted_time(&self) -> String {
         use chrono::{DateTime, Local, TimeZone, Utc};
@@ -98,27 +305,79 @@ impl Transaction {
     }
 }
 
+/// Amounts are kept as numbers but serialized as decimal strings, as they always have been
+mod amount_serde {
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+    /// Wei as an exact ether string, e.g. "1.5"
+    pub mod ether {
+        use super::*;
+        use crate::model::units::{format_amount, AmountFormat};
+        use alloy::primitives::utils::parse_ether;
+        use alloy::primitives::U256;
+
+        pub fn serialize<S: Serializer>(wei: &U256, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.serialize_str(&format_amount(*wei, 18, &AmountFormat::EXACT))
+        }
+
+        pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<U256, D::Error> {
+            let text = String::deserialize(deserializer)?;
+            parse_ether(&text).map_err(|e| D::Error::custom(format!("invalid ether amount {:?}: {}", text, e)))
+        }
+    }
+
+    /// Integer quantity as a decimal string
+    pub mod quantity {
+        use super::*;
+
+        pub fn serialize<S: Serializer>(value: &u128, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.collect_str(value)
+        }
+
+        pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u128, D::Error> {
+            let text = String::deserialize(deserializer)?;
+            text.parse().map_err(|_| D::Error::custom(format!("invalid quantity {:?}", text)))
+        }
+    }
+
+    pub mod opt_quantity {
+        use super::*;
+
+        pub fn serialize<S: Serializer>(value: &Option<u128>, serializer: S) -> Result<S::Ok, S::Error> {
+            match value {
+                Some(value) => serializer.collect_str(value),
+                None => serializer.serialize_none(),
+            }
+        }
+
+        pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<u128>, D::Error> {
+            Option::<String>::deserialize(deserializer)?
+                .map(|text| text.parse().map_err(|_| D::Error::custom(format!("invalid quantity {:?}", text))))
+                .transpose()
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_has_data() {
-        let mut tx = Transaction {
+    fn sample_transaction() -> Transaction {
+        Transaction {
             hash: "0x123".to_string(),
             from: "0x456".to_string(),
             to: Some("0x789".to_string()),
-            value: "0.5".to_string(),
-            gas_limit: "21000".to_string(),
-            gas_price: Some("30".to_string()),
+            value: U256::from(500_000_000_000_000_000u128),
+            gas_limit: 21000,
+            gas_price: Some(30),
             data: "0x".to_string(),
-            function_sig: None,
-            timestamp: 0,
-            block_number: None,
-            status: None,
-            gas_used: None,
-            effective_gas_price: None,
-        };
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_has_data() {
+        let mut tx = sample_transaction();
 
         // Empty data
         assert!(!tx.has_data());
@@ -134,25 +393,39 @@ mod tests {
 
     #[test]
     fn test_short_methods_no_allocation() {
-        let tx = Transaction {
-            hash: "0x123".to_string(),
-            from: "0x456".to_string(),
-            to: Some("0x789".to_string()),
-            value: "0.5".to_string(),
-            gas_limit: "21000".to_string(),
-            gas_price: Some("30".to_string()),
-            data: "0x".to_string(),
-            function_sig: None,
-            timestamp: 0,
-            block_number: None,
-            status: None,
-            gas_used: None,
-            effective_gas_price: None,
-        };
+        let tx = sample_transaction();
 
         // These should not allocate for short strings
         assert!(matches!(tx.short_hash(), Cow::Borrowed(_)));
         assert!(matches!(tx.short_from(), Cow::Borrowed(_)));
         assert!(matches!(tx.short_to(), Cow::Borrowed(_)));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_amounts_serialize_as_strings() {
+        let mut tx: Transaction = serde_json::from_value(serde_json::json!({
+            "hash": "0x1", "from": "0x2", "to": null, "value": "1.5", "gas_limit": "21000",
+            "gas_price": "30000000000", "data": "0x", "function_sig": null, "timestamp": 0,
+            "block_number": 7, "status": true, "gas_used": "21000", "effective_gas_price": null,
+        }))
+        .unwrap();
+        assert_eq!(tx.value, U256::from(1_500_000_000_000_000_000u128));
+        assert_eq!(tx.gas_price, Some(30_000_000_000));
+        assert_eq!(tx.fee_wei(), None);
+
+        tx.effective_gas_price = Some(2_000_000_000);
+        assert_eq!(tx.fee_wei(), Some(U256::from(42_000_000_000_000u128)));
+
+        let json = serde_json::to_value(&tx).unwrap();
+        assert_eq!(json["value"], "1.5");
+        assert_eq!(json["gas_limit"], "21000");
+        assert_eq!(json["effective_gas_price"], "2000000000");
+        assert_eq!(serde_json::from_value::<Transaction>(json).unwrap().value, tx.value);
+
+        // Fields added later default when absent
+        assert_eq!(tx.type_name(), "Legacy");
+        assert_eq!(tx.lifecycle(Some(6)), Lifecycle::Mined);
+        assert_eq!(tx.lifecycle(Some(7)), Lifecycle::Finalized);
+        assert!(tx.max_fee_per_gas.is_none() && tx.access_list.is_empty());
+    }
+}
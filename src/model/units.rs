@@ -0,0 +1,164 @@
+use alloy::primitives::U256;
+
+/// Decimals of a gwei relative to wei
+const GWEI_DECIMALS: u8 = 9;
+
+/// Unit an amount of wei is displayed in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Denomination {
+    #[default]
+    Native, // Ether or the chain's native token
+    Gwei,
+    Wei,
+}
+
+impl Denomination {
+    /// Decimals to shift a wei amount by, given the native token's decimals
+    pub fn decimals(self, native_decimals: u8) -> u8 {
+        match self {
+            Self::Native => native_decimals,
+            Self::Gwei => GWEI_DECIMALS,
+            Self::Wei => 0,
+        }
+    }
+
+    pub fn label(self, native_symbol: &str) -> &str {
+        match self {
+            Self::Native => native_symbol,
+            Self::Gwei => "Gwei",
+            Self::Wei => "wei",
+        }
+    }
+
+    /// Cycle native → Gwei → wei
+    pub fn next(self) -> Self {
+        match self {
+            Self::Native => Self::Gwei,
+            Self::Gwei => Self::Wei,
+            Self::Wei => Self::Native,
+        }
+    }
+}
+
+/// How amounts are rounded and grouped for display
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AmountFormat {
+    pub significant_digits: u8, // Fraction digits are rounded to this many significant digits
+    pub full_precision: bool, // Show every digit, ignoring `significant_digits`
+    pub separators: bool, // Group the integer part in thousands
+}
+
+impl Default for AmountFormat {
+    fn default() -> Self {
+        Self {
+            significant_digits: 6,
+            full_precision: false,
+            separators: true,
+        }
+    }
+}
+
+impl AmountFormat {
+    /// Exact digits without grouping, for machine-readable output
+    pub const EXACT: Self = Self {
+        significant_digits: 0,
+        full_precision: true,
+        separators: false,
+    };
+}
+
+/// Format `amount` base units as a decimal with `decimals` places, e.g. wei as ether.
+/// The integer part is always shown in full; trailing fraction zeros are dropped.
+pub fn format_amount(amount: U256, decimals: u8, format: &AmountFormat) -> String {
+    let decimals = decimals.min(77); // 10^78 overflows U256
+    let scale = U256::from(10).pow(U256::from(decimals));
+
+    let fraction_digits = if format.full_precision {
+        decimals
+    } else {
+        rounded_fraction_digits(amount, scale, decimals, format.significant_digits)
+    };
+
+    // Round half up to the kept fraction digits
+    let divisor = U256::from(10).pow(U256::from(decimals - fraction_digits));
+    let mut kept = amount / divisor;
+    if divisor > U256::from(1) && amount % divisor >= divisor / U256::from(2) {
+        kept = kept.saturating_add(U256::from(1));
+    }
+
+    let fraction_scale = U256::from(10).pow(U256::from(fraction_digits));
+    let integer = (kept / fraction_scale).to_string();
+    let fraction = format!("{:0>width$}", (kept % fraction_scale).to_string(), width = fraction_digits as usize);
+    let fraction = fraction.trim_end_matches('0');
+
+    let integer = if format.separators {
+        group_thousands(&integer)
+    } else {
+        integer
+    };
+    if fraction.is_empty() {
+        integer
+    } else {
+        format!("{}.{}", integer, fraction)
+    }
+}
+
+/// Fraction digits needed for `significant` significant digits overall
+fn rounded_fraction_digits(amount: U256, scale: U256, decimals: u8, significant: u8) -> u8 {
+    let integer = amount / scale;
+    let digits = if integer.is_zero() {
+        // Leading zeros of the fraction don't count as significant
+        let fraction = amount % scale;
+        if fraction.is_zero() {
+            return 0;
+        }
+        let leading_zeros = decimals as usize - fraction.to_string().len();
+        leading_zeros + significant as usize
+    } else {
+        (significant as usize).saturating_sub(integer.to_string().len())
+    };
+    digits.min(decimals as usize) as u8
+}
+
+fn group_thousands(digits: &str) -> String {
+    let mut grouped = String::with_capacity(digits.len() + digits.len() / 3);
+    for (i, c) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            grouped.push(',');
+        }
+        grouped.push(c);
+    }
+    grouped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ether(wei: u128) -> String {
+        format_amount(U256::from(wei), 18, &AmountFormat::default())
+    }
+
+    #[test]
+    fn test_significant_digits_and_separators() {
+        assert_eq!(ether(0), "0");
+        assert_eq!(ether(1_500_000_000_000_000_000), "1.5");
+        assert_eq!(ether(1_234_567_891_234_567_891_234), "1,234.57");
+        // Small values keep their significant digits instead of showing 0.000000
+        assert_eq!(ether(12_345_678), "0.0000000000123457");
+        assert_eq!(ether(999_999_999_999_999_999), "1");
+        assert_eq!(format_amount(U256::from(30_123_456_789u64), 9, &AmountFormat::default()), "30.1235");
+
+        // Values far beyond u128 don't panic
+        let huge = format_amount(U256::MAX, 18, &AmountFormat::default());
+        assert!(huge.starts_with("115,792,089,237"));
+    }
+
+    #[test]
+    fn test_full_precision() {
+        let full = AmountFormat { full_precision: true, ..AmountFormat::default() };
+        assert_eq!(format_amount(U256::from(1_234_567_891_234_567_891_234u128), 18, &full), "1,234.567891234567891234");
+        assert_eq!(format_amount(U256::from(1_000_000u64), 0, &AmountFormat::EXACT), "1000000");
+        assert_eq!(format_amount(U256::from(1u64), 18, &AmountFormat::EXACT), "0.000000000000000001");
+    }
+}
@@ -1,41 +1,342 @@
-use alloy::providers::{Provider, ProviderBuilder, WsConnect};
-use alloy::rpc::types::{Transaction as AlloyTransaction, TransactionReceipt};
-use anyhow::{Context, Result};
+use alloy::eips::{BlockId, BlockNumberOrTag};
+use alloy::primitives::TxHash;
+use alloy::providers::{Provider, ProviderBuilder, RootProvider, WsConnect};
+use alloy::pubsub::Subscription;
+use alloy::rpc::types::{Block, Header, Transaction as AlloyTransaction, TransactionReceipt};
+use alloy::transports::BoxTransport;
+use anyhow::{anyhow, Context, Result};
+use futures::StreamExt;
+use std::collections::{HashMap, HashSet};
+use std::ops::RangeInclusive;
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
 use tokio::sync::mpsc;
 
+use super::pipeline::{FetchCounters, FetchOptions, FetchPipeline};
+
+/// Consecutive request failures after which the connection is considered unhealthy
+const MAX_CONSECUTIVE_FAILURES: u32 = 3;
+
+/// Mined transactions buffered between the block task and the UI
+const BLOCK_CHANNEL_SIZE: usize = 1000;
+
+/// Default interval between polls on HTTP endpoints
+pub const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Shortest accepted poll interval; a zero period would panic in `tokio::time::interval`
+pub const MIN_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Most blocks fetched in one poll when catching up after a gap
+const MAX_CATCH_UP_BLOCKS: u64 = 8;
+
+/// A mined block as seen by the lifecycle tracker
+#[derive(Debug, Clone)]
+pub struct MinedBlock {
+    pub number: u64,
+    pub timestamp: u64, // Unix seconds
+    pub hashes: Vec<String>,
+}
+
+/// The client of the current connection, shared between the subscription task
+/// and on-demand lookups. `None` while disconnected.
+pub type SharedClient = Arc<RwLock<Option<RpcClient>>>;
+
+/// The current client, if connected and healthy
+pub fn current_client(shared_client: &SharedClient) -> Option<RpcClient> {
+    shared_client
+        .read()
+        .ok()
+        .and_then(|guard| guard.clone())
+        .filter(|client| client.health().is_healthy())
+}
+
+/// Requests from the UI to the connection task
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RpcCommand {
+    /// Drop the current connection and reconnect immediately, skipping any backoff
+    Reconnect,
+}
+
+/// Where the transaction stream comes from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SourceMode {
+    /// Pending transactions from the mempool
+    Mempool,
+    /// Transactions of newly mined blocks, with receipt data
+    Blocks,
+}
+
+impl SourceMode {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.to_ascii_lowercase().as_str() {
+            "mempool" | "pending" => Some(Self::Mempool),
+            "blocks" | "mined" => Some(Self::Blocks),
+            _ => None,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Mempool => "Mempool",
+            Self::Blocks => "Blocks",
+        }
+    }
+}
+
+/// How pending transactions are requested from the node
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PendingTxMode {
+    /// Try each full-object subscription, then fall back to hashes
+    Auto,
+    /// Subscribe to hashes and fetch each transaction
+    Hashes,
+    /// `newPendingTransactions` with the full-object flag (Geth 1.11+, Erigon), no fallback
+    Full,
+    /// Alchemy's `alchemy_pendingTransactions`, no fallback
+    Alchemy,
+}
+
+impl PendingTxMode {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.to_ascii_lowercase().as_str() {
+            "auto" => Some(Self::Auto),
+            "hashes" | "hash" => Some(Self::Hashes),
+            "full" => Some(Self::Full),
+            "alchemy" => Some(Self::Alchemy),
+            _ => None,
+        }
+    }
+}
+
+/// Transport chosen from the RPC URL scheme
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TransportKind {
+    /// `ws://` / `wss://` - push subscriptions
+    WebSocket,
+    /// `http://` / `https://` - filter and block-number polling
+    Http,
+}
+
+impl TransportKind {
+    fn from_url(url: &str) -> Self {
+        let scheme = url.split("://").next().unwrap_or_default().to_ascii_lowercase();
+        if scheme == "http" || scheme == "https" {
+            Self::Http
+        } else {
+            Self::WebSocket
+        }
+    }
+}
+
+/// Connection to an RPC endpoint. Cloning is cheap and shares the underlying socket.
+#[derive(Clone)]
 pub struct RpcClient {
-    rpc_url: String,
+    provider: RootProvider<BoxTransport>,
+    health: Arc<ConnectionHealth>,
+    transport: TransportKind,
+    poll_interval: Duration,
+    chain_id: u64,
+}
+
+/// Health of a connection, updated by every request made through it
+#[derive(Debug)]
+pub struct ConnectionHealth {
+    alive: AtomicBool,
+    consecutive_failures: AtomicU32,
+}
+
+impl ConnectionHealth {
+    fn new() -> Self {
+        Self {
+            alive: AtomicBool::new(true),
+            consecutive_failures: AtomicU32::new(0),
+        }
+    }
+
+    pub(super) fn record_success(&self) {
+        self.consecutive_failures.store(0, Ordering::Relaxed);
+    }
+
+    pub(super) fn record_failure(&self) {
+        self.consecutive_failures.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Mark the connection as closed, e.g. when its subscription ends
+    fn mark_dead(&self) {
+        self.alive.store(false, Ordering::Relaxed);
+    }
+
+    /// Whether the connection is open and requests are succeeding
+    pub fn is_healthy(&self) -> bool {
+        self.alive.load(Ordering::Relaxed)
+            && self.consecutive_failures.load(Ordering::Relaxed) < MAX_CONSECUTIVE_FAILURES
+    }
 }
 
 impl RpcClient {
+    /// Open the connection that all requests and subscriptions share.
+    /// The transport is picked from the URL scheme: WebSocket or HTTP polling.
     pub async fn connect(url: &str) -> Result<Self> {
+        let transport = TransportKind::from_url(url);
+        let provider = match transport {
+            TransportKind::WebSocket => ProviderBuilder::new()
+                .on_ws(WsConnect::new(url))
+                .await
+                .context("Failed to connect to WebSocket")?
+                .boxed(),
+            TransportKind::Http => ProviderBuilder::new()
+                .on_http(url.parse().context("Invalid HTTP RPC URL")?)
+                .boxed(),
+        };
+        // Also makes sure an HTTP endpoint actually answers, as it has no handshake
+        let chain_id = provider
+            .get_chain_id()
+            .await
+            .context("Failed to query chain ID")?;
+
         Ok(Self {
-            rpc_url: url.to_string(),
+            provider,
+            health: Arc::new(ConnectionHealth::new()),
+            transport,
+            poll_interval: DEFAULT_POLL_INTERVAL,
+            chain_id,
         })
     }
 
-    /// Fetch a specific transaction by hash with receipt data
-    pub async fn fetch_transaction_by_hash(&self, tx_hash: &str) -> Result<Option<crate::model::Transaction>> {
-        // Create provider
-        let ws = WsConnect::new(&self.rpc_url);
-        let provider = ProviderBuilder::new()
-            .on_ws(ws)
+    /// Chain ID reported by the endpoint on connect
+    pub fn chain_id(&self) -> u64 {
+        self.chain_id
+    }
+
+    /// Set how often HTTP endpoints are polled for new transactions or blocks
+    pub fn with_poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    /// Health of this connection
+    pub fn health(&self) -> &ConnectionHealth {
+        &self.health
+    }
+
+    /// Heartbeat: `eth_blockNumber` with a deadline, recorded in the connection health
+    pub async fn ping(&self, timeout: Duration) -> Result<u64> {
+        match tokio::time::timeout(timeout, self.provider.get_block_number()).await {
+            Ok(Ok(number)) => {
+                self.health.record_success();
+                Ok(number)
+            }
+            Ok(Err(e)) => {
+                self.health.record_failure();
+                Err(e).context("Ping failed")
+            }
+            Err(_) => {
+                self.health.record_failure();
+                Err(anyhow!("Ping timed out"))
+            }
+        }
+    }
+
+    pub async fn block_number(&self) -> Result<u64> {
+        self.provider.get_block_number().await.context("Failed to fetch block number")
+    }
+
+    /// Latest block the node considers final
+    pub async fn finalized_block_number(&self) -> Result<u64> {
+        let block = self
+            .provider
+            .get_block_by_number(BlockNumberOrTag::Finalized, false)
+            .await
+            .context("Failed to fetch finalized block")?
+            .context("Node has no finalized block")?;
+        Ok(block.header.number)
+    }
+
+    /// Fetch a mined block's transaction hashes
+    pub async fn fetch_mined_block(&self, number: u64) -> Result<MinedBlock> {
+        let block = self
+            .provider
+            .get_block_by_number(BlockNumberOrTag::Number(number), false)
             .await
-            .context("Failed to connect to WebSocket")?;
+            .context("Failed to fetch block")?
+            .context("Block not found")?;
+
+        Ok(MinedBlock {
+            number,
+            timestamp: block.header.timestamp,
+            hashes: block.transactions.hashes().map(|hash| format!("{:#x}", hash)).collect(),
+        })
+    }
 
+    /// Receipts of some transactions in a block. Uses `eth_getBlockReceipts` and falls
+    /// back to one request per wanted transaction.
+    pub async fn fetch_receipts(&self, block_number: u64, hashes: &[String]) -> Result<Vec<TransactionReceipt>> {
+        if let Ok(Some(receipts)) = self.provider.get_block_receipts(BlockId::number(block_number)).await {
+            let wanted: HashSet<&str> = hashes.iter().map(String::as_str).collect();
+            return Ok(receipts
+                .into_iter()
+                .filter(|receipt| wanted.contains(format!("{:#x}", receipt.transaction_hash).as_str()))
+                .collect());
+        }
+
+        let hashes = hashes
+            .iter()
+            .map(|hash| hash.parse::<TxHash>())
+            .collect::<Result<Vec<_>, _>>()
+            .context("Invalid transaction hash")?;
+        let requests = hashes.into_iter().map(|hash| self.provider.get_transaction_receipt(hash));
+        Ok(futures::future::join_all(requests)
+            .await
+            .into_iter()
+            .filter_map(|receipt| receipt.ok().flatten())
+            .collect())
+    }
+
+    /// Receipts of transactions whose block isn't known. Fails if any lookup fails,
+    /// so a missing receipt means the node has none.
+    pub async fn fetch_receipts_by_hash(&self, hashes: &[String]) -> Result<Vec<TransactionReceipt>> {
+        let hashes = hashes
+            .iter()
+            .map(|hash| hash.parse::<TxHash>())
+            .collect::<Result<Vec<_>, _>>()
+            .context("Invalid transaction hash")?;
+        let requests = hashes.into_iter().map(|hash| self.provider.get_transaction_receipt(hash));
+        match futures::future::try_join_all(requests).await {
+            Ok(receipts) => {
+                self.health.record_success();
+                Ok(receipts.into_iter().flatten().collect())
+            }
+            Err(e) => {
+                self.health.record_failure();
+                Err(e).context("Failed to fetch receipts")
+            }
+        }
+    }
+
+    /// Fetch a specific transaction by hash with receipt data
+    pub async fn fetch_transaction_by_hash(&self, tx_hash: &str) -> Result<Option<crate::model::Transaction>> {
         // Parse the transaction hash
         let hash = tx_hash.parse().context("Invalid transaction hash")?;
 
         // Try to fetch the transaction
-        let tx_data = provider.get_transaction_by_hash(hash).await
-            .context("Failed to fetch transaction")?;
+        let tx_data = match self.provider.get_transaction_by_hash(hash).await {
+            Ok(tx_data) => {
+                self.health.record_success();
+                tx_data
+            }
+            Err(e) => {
+                self.health.record_failure();
+                return Err(e).context("Failed to fetch transaction");
+            }
+        };
 
         if let Some(tx) = tx_data {
             // Parse basic transaction data
             let mut transaction = parse_transaction(tx)?;
 
             // Try to fetch the receipt for additional data
-            if let Ok(Some(receipt)) = provider.get_transaction_receipt(hash).await {
+            if let Ok(Some(receipt)) = self.provider.get_transaction_receipt(hash).await {
                 transaction = enhance_with_receipt(transaction, receipt);
             }
 
@@ -45,124 +346,602 @@ impl RpcClient {
         }
     }
 
-    pub async fn subscribe_pending_txs(&self) -> Result<mpsc::UnboundedReceiver<crate::model::Transaction>> {
-        let (tx, rx) = mpsc::unbounded_channel();
+    /// Subscribe to pending transactions. Full objects pushed by the node are used
+    /// directly; bare hashes go through the bounded fetch pipeline.
+    pub async fn subscribe_pending_txs(
+        &self,
+        mode: PendingTxMode,
+        options: FetchOptions,
+        counters: Arc<FetchCounters>,
+    ) -> Result<mpsc::Receiver<crate::model::Transaction>> {
+        if self.transport == TransportKind::Http {
+            return self.poll_pending_txs(options, counters).await;
+        }
+
+        // Subscribe on the shared connection
+        let mut sub = self.open_pending_subscription(mode).await?;
+
+        let (mut pipeline, rx) = FetchPipeline::spawn(self.provider.clone(), self.health.clone(), options, counters);
+        let health = self.health.clone();
+
+        // Spawn a task to route subscription items
+        spawn_feed(self.health.clone(), pipeline.output(), async move {
+            // Subscription error ends the loop - connection likely dropped
+            while let Ok(item) = sub.recv().await {
+                health.record_success();
+
+                match PendingItem::from_value(item) {
+                    Some(PendingItem::Hash(hash)) => pipeline.submit(hash),
+                    Some(PendingItem::Full(tx)) => pipeline.deliver(*tx),
+                    None => {}
+                }
+            }
+        });
+
+        Ok(rx)
+    }
+
+    /// Subscribe to new heads and stream each mined block's transactions with receipts
+    pub async fn subscribe_block_txs(&self) -> Result<mpsc::Receiver<crate::model::Transaction>> {
+        if self.transport == TransportKind::Http {
+            return self.poll_block_txs().await;
+        }
 
-        // Create a new provider for the subscription
-        let ws = WsConnect::new(&self.rpc_url);
-        let provider = ProviderBuilder::new()
-            .on_ws(ws)
+        let mut sub = self
+            .provider
+            .subscribe::<_, Header>(("newHeads",))
             .await
-            .context("Failed to connect to WebSocket")?;
-
-        // Spawn a task to handle subscriptions
-        tokio::spawn(async move {
-            // Subscribe to pending transactions
-            match provider.subscribe_pending_transactions().await {
-                Ok(mut sub) => {
-                    loop {
-                        match sub.recv().await {
-                            Ok(tx_hash) => {
-                                // Fetch full transaction details
-                                if let Ok(Some(tx_data)) = provider.get_transaction_by_hash(tx_hash).await {
-                                    if let Ok(parsed_tx) = parse_transaction(tx_data) {
-                                        let _ = tx.send(parsed_tx);
-                                    }
-                                }
-                            }
-                            Err(_) => {
-                                // Subscription error - connection likely dropped
-                                break;
+            .context("Failed to subscribe to new blocks")?;
+
+        let (tx, rx) = mpsc::channel(BLOCK_CHANNEL_SIZE);
+        let client = self.clone();
+
+        // Blocks are handled one at a time so transactions arrive in chain order
+        spawn_feed(self.health.clone(), tx.clone(), async move {
+            while let Ok(header) = sub.recv().await {
+                client.health.record_success();
+
+                match client.fetch_block_transactions(header.number).await {
+                    Ok(transactions) => {
+                        for transaction in transactions {
+                            if tx.send(transaction).await.is_err() {
+                                return; // Receiver dropped
                             }
                         }
                     }
+                    Err(_) => client.health.record_failure(),
                 }
-                Err(_) => {
-                    // Failed to subscribe - will be handled by caller
+            }
+        });
+
+        Ok(rx)
+    }
+
+    /// Poll a pending transaction filter (`eth_getFilterChanges`) and fetch the new hashes
+    async fn poll_pending_txs(
+        &self,
+        options: FetchOptions,
+        counters: Arc<FetchCounters>,
+    ) -> Result<mpsc::Receiver<crate::model::Transaction>> {
+        let mut batches = self
+            .provider
+            .watch_pending_transactions()
+            .await
+            .context("Failed to create pending transaction filter (try SOURCE_MODE=blocks)")?
+            .with_poll_interval(self.poll_interval)
+            .into_stream();
+
+        let (mut pipeline, rx) = FetchPipeline::spawn(self.provider.clone(), self.health.clone(), options, counters);
+        let health = self.health.clone();
+
+        // The poller stops on a failed poll, e.g. when the node forgets the filter
+        spawn_feed(self.health.clone(), pipeline.output(), async move {
+            while let Some(hashes) = batches.next().await {
+                health.record_success();
+                for hash in hashes {
+                    pipeline.submit(hash);
                 }
             }
         });
 
         Ok(rx)
     }
+
+    /// Poll `eth_blockNumber` and stream the transactions of each new block
+    async fn poll_block_txs(&self) -> Result<mpsc::Receiver<crate::model::Transaction>> {
+        let mut last = self
+            .provider
+            .get_block_number()
+            .await
+            .context("Failed to fetch block number")?
+            .saturating_sub(1); // Start with the current head
+
+        let (tx, rx) = mpsc::channel(BLOCK_CHANNEL_SIZE);
+        let client = self.clone();
+
+        spawn_feed(self.health.clone(), tx.clone(), async move {
+            let mut ticker = tokio::time::interval(client.poll_interval);
+            while client.health.is_healthy() {
+                ticker.tick().await;
+
+                let head = match client.provider.get_block_number().await {
+                    Ok(head) => {
+                        client.health.record_success();
+                        head
+                    }
+                    Err(_) => {
+                        client.health.record_failure();
+                        continue;
+                    }
+                };
+
+                for number in catch_up_range(last, head) {
+                    let Ok(transactions) = client.fetch_block_transactions(number).await else {
+                        client.health.record_failure();
+                        break; // Retry from this block on the next poll
+                    };
+                    for transaction in transactions {
+                        if tx.send(transaction).await.is_err() {
+                            return; // Receiver dropped
+                        }
+                    }
+                    last = number;
+                }
+            }
+        });
+
+        Ok(rx)
+    }
+
+    /// Fetch a mined block with full transactions and attach their receipts
+    pub async fn fetch_block_transactions(&self, number: u64) -> Result<Vec<crate::model::Transaction>> {
+        let block = self
+            .provider
+            .get_block_by_number(BlockNumberOrTag::Number(number), true)
+            .await
+            .context("Failed to fetch block")?
+            .context("Block not found")?;
+
+        let receipts = self.fetch_block_receipts(&block).await;
+        block_transactions(block, receipts)
+    }
+
+    /// Receipts for every transaction in a block, keyed by transaction hash.
+    /// Uses `eth_getBlockReceipts` and falls back to one request per transaction.
+    async fn fetch_block_receipts(&self, block: &Block) -> HashMap<TxHash, TransactionReceipt> {
+        let block_id = BlockId::number(block.header.number);
+        let receipts = match self.provider.get_block_receipts(block_id).await {
+            Ok(Some(receipts)) => receipts,
+            _ => {
+                let requests = block
+                    .transactions
+                    .hashes()
+                    .map(|hash| self.provider.get_transaction_receipt(hash));
+                futures::future::join_all(requests)
+                    .await
+                    .into_iter()
+                    .filter_map(|receipt| receipt.ok().flatten())
+                    .collect()
+            }
+        };
+
+        receipts
+            .into_iter()
+            .map(|receipt| (receipt.transaction_hash, receipt))
+            .collect()
+    }
+
+    /// Open the pending subscription for `mode`. Only `Auto` falls back to hashes; an
+    /// explicit `Full` or `Alchemy` fails if the node refuses it.
+    async fn open_pending_subscription(&self, mode: PendingTxMode) -> Result<Subscription<serde_json::Value>> {
+        match mode {
+            PendingTxMode::Full => {
+                return self
+                    .provider
+                    .subscribe(("newPendingTransactions", true))
+                    .await
+                    .context("Node refused full-object newPendingTransactions (try PENDING_TX_MODE=auto)");
+            }
+            PendingTxMode::Alchemy => {
+                let params = ("alchemy_pendingTransactions", serde_json::json!({ "hashesOnly": false }));
+                return self
+                    .provider
+                    .subscribe(params)
+                    .await
+                    .context("Node refused alchemy_pendingTransactions (try PENDING_TX_MODE=auto)");
+            }
+            PendingTxMode::Auto => {
+                if let Ok(sub) = self.provider.subscribe(("newPendingTransactions", true)).await {
+                    return Ok(sub);
+                }
+                let params = ("alchemy_pendingTransactions", serde_json::json!({ "hashesOnly": false }));
+                if let Ok(sub) = self.provider.subscribe(params).await {
+                    return Ok(sub);
+                }
+            }
+            PendingTxMode::Hashes => {}
+        }
+
+        self.provider
+            .subscribe(("newPendingTransactions",))
+            .await
+            .context("Failed to subscribe to pending transactions")
+    }
 }
 
-fn parse_transaction(tx: AlloyTransaction) -> Result<crate::model::Transaction> {
-    use crate::model::Transaction;
+/// A pending subscription item: nodes may ignore the full-object flag, so either shape can arrive
+#[derive(Debug)]
+enum PendingItem {
+    Hash(TxHash),
+    Full(Box<AlloyTransaction>),
+}
+
+impl PendingItem {
+    /// Route a raw subscription item; `None` for anything that is neither a hash nor a transaction
+    fn from_value(item: serde_json::Value) -> Option<Self> {
+        if item.is_string() {
+            serde_json::from_value(item).ok().map(Self::Hash)
+        } else {
+            serde_json::from_value(item).ok().map(|tx| Self::Full(Box::new(tx)))
+        }
+    }
+}
+
+/// A mined block's transactions, stamped with the block and completed from their receipts
+fn block_transactions(
+    block: Block,
+    mut receipts: HashMap<TxHash, TransactionReceipt>,
+) -> Result<Vec<crate::model::Transaction>> {
+    let number = block.header.number;
+    let timestamp = block.header.timestamp as i64;
+
+    let mut transactions = Vec::with_capacity(block.transactions.len());
+    for tx_data in block.transactions.into_transactions() {
+        let hash = tx_data.hash;
+        let mut transaction = parse_transaction(tx_data)?;
+
+        // Mined transactions are stamped with the block time rather than arrival time
+        transaction.timestamp = timestamp;
+        transaction.block_number = Some(number);
+
+        if let Some(receipt) = receipts.remove(&hash) {
+            transaction = enhance_with_receipt(transaction, receipt);
+        }
+        transactions.push(transaction);
+    }
+
+    Ok(transactions)
+}
+
+/// Blocks to fetch after `last` up to `head`. After a long gap this skips ahead to
+/// the newest `MAX_CATCH_UP_BLOCKS` rather than replaying history; empty when caught up.
+pub(super) fn catch_up_range(last: u64, head: u64) -> RangeInclusive<u64> {
+    (last + 1).max(head.saturating_sub(MAX_CATCH_UP_BLOCKS - 1))..=head
+}
+
+/// Run a subscription feed until it ends or its receiver is dropped, which is how a
+/// forced reconnect tears it down, then mark the connection dead
+fn spawn_feed<F>(health: Arc<ConnectionHealth>, output: mpsc::Sender<crate::model::Transaction>, feed: F)
+where
+    F: std::future::Future<Output = ()> + Send + 'static,
+{
+    tokio::spawn(async move {
+        tokio::select! {
+            _ = feed => {}
+            _ = output.closed() => {}
+        }
+        health.mark_dead();
+    });
+}
+
+pub(super) fn parse_transaction(tx: AlloyTransaction) -> Result<crate::model::Transaction> {
+    use crate::model::{AccessListEntry, Transaction, TxSignature};
 
     let hash = format!("{:#x}", tx.hash);
     let from = format!("{:#x}", tx.from);
     let to = tx.to.map(|addr| format!("{:#x}", addr));
 
-    // Handle value field
-    let value = format_ether(tx.value);
-
-    // Get gas limit
-    let gas_limit = tx.gas.to_string();
-
-    // Get gas price (might be None for EIP-1559 txs)
-    let gas_price = tx.gas_price.map(|p| p.to_string());
+    let access_list = tx
+        .access_list
+        .as_ref()
+        .map(|list| {
+            list.iter()
+                .map(|item| AccessListEntry {
+                    address: format!("{:#x}", item.address),
+                    storage_keys: item.storage_keys.iter().map(|key| format!("{:#x}", key)).collect(),
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    let blob_versioned_hashes = tx
+        .blob_versioned_hashes
+        .as_deref()
+        .unwrap_or_default()
+        .iter()
+        .map(|hash| format!("{:#x}", hash))
+        .collect();
 
     // Get input data
     let data = format!("0x{}", hex::encode(tx.input.as_ref()));
 
-    let function_sig = crate::model::decoder::decode_function(&data);
-    let timestamp = chrono::Utc::now().timestamp();
+    // Decode with the ABI bound to the target contract first, then by selector
+    let function_sig = crate::model::decoder::decode_call(to.as_deref(), &data);
+    let to_label = to.as_deref().and_then(crate::model::decoder::contract_label);
+    let now = chrono::Utc::now();
 
     Ok(Transaction {
         hash,
         from,
+        nonce: tx.nonce,
         to,
-        value,
-        gas_limit,
-        gas_price,
+        value: tx.value,
+        gas_limit: tx.gas,
+        gas_price: tx.gas_price, // Might be None for EIP-1559 txs
+        tx_type: tx.transaction_type.unwrap_or(0),
+        max_fee_per_gas: tx.max_fee_per_gas,
+        max_priority_fee_per_gas: tx.max_priority_fee_per_gas,
+        max_fee_per_blob_gas: tx.max_fee_per_blob_gas,
+        access_list,
+        blob_versioned_hashes,
+        chain_id: tx.chain_id,
+        signature: tx.signature.as_ref().map(|signature| TxSignature {
+            v: signature.v.saturating_to(),
+            r: format!("{:#x}", signature.r),
+            s: format!("{:#x}", signature.s),
+            y_parity: signature.y_parity.map(|parity| parity.0),
+        }),
         data,
         function_sig,
-        timestamp,
+        to_label,
+        timestamp: now.timestamp(),
+        first_seen_ms: now.timestamp_millis(),
+        included_ms: None,
+        replaces: None,
+        fee_bump_pct: None,
+        replaced_by: None,
+        dropped: false,
         block_number: None,
         status: None,
         gas_used: None,
         effective_gas_price: None,
+        logs: Vec::new(),
+        seen_by: Vec::new(),
+        raw: Some(Arc::new(tx)), // Kept as is; pretty-printed only when the raw view opens
     })
 }
 
-fn enhance_with_receipt(mut tx: crate::model::Transaction, receipt: TransactionReceipt) -> crate::model::Transaction {
+pub fn enhance_with_receipt(mut tx: crate::model::Transaction, receipt: TransactionReceipt) -> crate::model::Transaction {
     // Add receipt data to transaction
     tx.block_number = receipt.block_number;
 
     // Check status (successful if status is true/1)
     tx.status = Some(receipt.status());
 
-    // Format gas used
-    tx.gas_used = Some(receipt.gas_used.to_string());
+    tx.gas_used = Some(receipt.gas_used);
+
+    // Effective gas price is always present in receipts
+    tx.effective_gas_price = Some(receipt.effective_gas_price);
 
-    // Format effective gas price (it's always present in receipts)
-    tx.effective_gas_price = Some(receipt.effective_gas_price.to_string());
+    // Decode emitted events
+    tx.logs = receipt
+        .inner
+        .logs()
+        .iter()
+        .map(|log| crate::model::decoder::decode_log(&log.inner.address, log.topics(), &log.inner.data.data))
+        .collect();
 
     tx
 }
 
-fn format_ether(wei: alloy::primitives::U256) -> String {
-    // Convert wei to ether (1 ether = 10^18 wei)
-    const WEI_PER_ETHER: u128 = 1_000_000_000_000_000_000;
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    // Convert U256 to u128 (safe for most transaction values)
-    let wei_u128 = wei.to::<u128>();
+    fn legacy_tx_json() -> serde_json::Value {
+        serde_json::json!({
+            "hash": "0x88df016429689c079f3b2f6ad39fa052532c56795b733da78a91ebe6a713944b",
+            "nonce": "0x15",
+            "blockHash": null,
+            "blockNumber": null,
+            "transactionIndex": null,
+            "from": "0xa7d9ddbe1f17865597fbd27ec712455208b6b76d",
+            "to": "0xf02c1c8e6114b1dbe8937a39260b5b0a374432bb",
+            "value": "0xf3dbb76162000",
+            "gasPrice": "0x4a817c800",
+            "gas": "0x5208",
+            "input": "0x",
+            "v": "0x25",
+            "r": "0x1b5e176d927f8e9ab405058b2d2457392da3e20f328b16ddabcebc33eaac5fea",
+            "s": "0x4ba69724e8f69de52f0125ad8b3c5c2cef33019bac3249e2c0a2192766d1721c",
+            "type": "0x0"
+        })
+    }
+
+    fn eip1559_tx_json() -> serde_json::Value {
+        serde_json::json!({
+            "hash": "0xa8a3ff6a37cbe4a8e1a0b6e4e7b9ee1f4c6b6f1f9e5c4e5a2d8b1f2c3d4e5f60",
+            "nonce": "0x2a",
+            "blockHash": null,
+            "blockNumber": null,
+            "transactionIndex": null,
+            "from": "0xa7d9ddbe1f17865597fbd27ec712455208b6b76d",
+            "to": "0x7a250d5630b4cf539739df2c5dacb4c659f2488d",
+            "value": "0x0",
+            "gas": "0x30d40",
+            "gasPrice": "0x3b9aca00",
+            "maxFeePerGas": "0x77359400",
+            "maxPriorityFeePerGas": "0x3b9aca00",
+            "input": "0x",
+            "accessList": [{
+                "address": "0xc02aaa39b223fe8d0a0e5c4f27ead9083c756cc2",
+                "storageKeys": ["0x0000000000000000000000000000000000000000000000000000000000000003"]
+            }],
+            "chainId": "0x2105",
+            "v": "0x1",
+            "yParity": "0x1",
+            "r": "0x1b5e176d927f8e9ab405058b2d2457392da3e20f328b16ddabcebc33eaac5fea",
+            "s": "0x4ba69724e8f69de52f0125ad8b3c5c2cef33019bac3249e2c0a2192766d1721c",
+            "type": "0x2"
+        })
+    }
+
+    #[test]
+    fn test_parse_eip1559_transaction() {
+        let tx = parse_transaction(serde_json::from_value(eip1559_tx_json()).unwrap()).unwrap();
+        assert_eq!(tx.nonce, 42);
+        assert_eq!(tx.chain_id, Some(8453));
+        assert_eq!(tx.gas_limit, 200_000);
+
+        let signature = tx.signature.as_ref().unwrap();
+        assert_eq!(signature.v, 1);
+        assert_eq!(signature.r, "0x1b5e176d927f8e9ab405058b2d2457392da3e20f328b16ddabcebc33eaac5fea");
+        assert_eq!(signature.s, "0x4ba69724e8f69de52f0125ad8b3c5c2cef33019bac3249e2c0a2192766d1721c");
+        assert_eq!(signature.y_parity, Some(true));
+
+        // The node's object is kept for the raw view
+        let raw = tx.raw.as_ref().unwrap();
+        assert_eq!(format!("{:#x}", raw.hash), tx.hash);
+        assert_eq!(serde_json::to_value(raw.as_ref()).unwrap()["chainId"], "0x2105");
+    }
+
+    #[test]
+    fn test_parse_typed_transactions() {
+        let parse = |json| parse_transaction(serde_json::from_value(json).unwrap()).unwrap();
+
+        let tx = parse(eip1559_tx_json());
+        assert_eq!(tx.tx_type, 2);
+        assert_eq!(tx.max_fee_per_gas, Some(2_000_000_000));
+        assert_eq!(tx.max_priority_fee_per_gas, Some(1_000_000_000));
+        assert_eq!(tx.max_fee_per_blob_gas, None);
+        assert_eq!(tx.access_list.len(), 1);
+        assert_eq!(tx.access_list[0].address, "0xc02aaa39b223fe8d0a0e5c4f27ead9083c756cc2");
+        assert_eq!(
+            tx.access_list[0].storage_keys,
+            ["0x0000000000000000000000000000000000000000000000000000000000000003"]
+        );
+        assert!(tx.blob_versioned_hashes.is_empty());
+
+        let mut blob = eip1559_tx_json();
+        blob["type"] = "0x3".into();
+        blob["accessList"] = serde_json::json!([]);
+        blob["maxFeePerBlobGas"] = "0x5".into();
+        blob["blobVersionedHashes"] =
+            serde_json::json!(["0x01a915e4d060149eb4365960e6a7a45f334393093061116b197e3240065ff2d8"]);
+        let tx = parse(blob);
+        assert_eq!(tx.tx_type, 3);
+        assert_eq!(tx.max_fee_per_blob_gas, Some(5));
+        assert!(tx.access_list.is_empty());
+        assert_eq!(
+            tx.blob_versioned_hashes,
+            ["0x01a915e4d060149eb4365960e6a7a45f334393093061116b197e3240065ff2d8"]
+        );
+
+        let tx = parse(legacy_tx_json());
+        assert_eq!(tx.tx_type, 0);
+        assert_eq!(tx.max_fee_per_gas, None);
+        assert!(tx.access_list.is_empty() && tx.blob_versioned_hashes.is_empty());
+    }
+
+    #[test]
+    fn test_block_transactions_attach_receipts() {
+        let block_hash = "0x2b6c3c3d7e6ff1dbc0b4e3a9a2ef59cf1d1a3c54d0b8a0ab6f6cf3a0f3e4c5d6";
+        let mined = |hash: &str, index: &str| {
+            let mut tx = legacy_tx_json();
+            tx["hash"] = hash.into();
+            tx["blockHash"] = block_hash.into();
+            tx["blockNumber"] = "0x64".into();
+            tx["transactionIndex"] = index.into();
+            tx
+        };
+        let receipted = "0x88df016429689c079f3b2f6ad39fa052532c56795b733da78a91ebe6a713944b";
+        let unreceipted = "0x99df016429689c079f3b2f6ad39fa052532c56795b733da78a91ebe6a713944b";
+
+        let block: Block = serde_json::from_value(serde_json::json!({
+            "hash": block_hash,
+            "parentHash": "0x0000000000000000000000000000000000000000000000000000000000000000",
+            "sha3Uncles": "0x1dcc4de8dec75d7aab85b567b6ccd41ad312451b948a7413f0a142fd40d49347",
+            "miner": "0x0000000000000000000000000000000000000000",
+            "stateRoot": "0x0000000000000000000000000000000000000000000000000000000000000000",
+            "transactionsRoot": "0x0000000000000000000000000000000000000000000000000000000000000000",
+            "receiptsRoot": "0x0000000000000000000000000000000000000000000000000000000000000000",
+            "logsBloom": format!("0x{}", "0".repeat(512)),
+            "difficulty": "0x0",
+            "number": "0x64",
+            "gasLimit": "0x1c9c380",
+            "gasUsed": "0xa410",
+            "timestamp": "0x6553f100",
+            "extraData": "0x",
+            "mixHash": "0x0000000000000000000000000000000000000000000000000000000000000000",
+            "nonce": "0x0000000000000000",
+            "uncles": [],
+            "transactions": [mined(receipted, "0x0"), mined(unreceipted, "0x1")]
+        }))
+        .unwrap();
+        let receipt: TransactionReceipt = serde_json::from_value(serde_json::json!({
+            "transactionHash": receipted,
+            "transactionIndex": "0x0",
+            "blockHash": block_hash,
+            "blockNumber": "0x64",
+            "from": "0xa7d9ddbe1f17865597fbd27ec712455208b6b76d",
+            "to": "0xf02c1c8e6114b1dbe8937a39260b5b0a374432bb",
+            "cumulativeGasUsed": "0x5208",
+            "gasUsed": "0x5208",
+            "effectiveGasPrice": "0x4a817c800",
+            "contractAddress": null,
+            "logs": [],
+            "logsBloom": format!("0x{}", "0".repeat(512)),
+            "status": "0x1",
+            "type": "0x0"
+        }))
+        .unwrap();
+        let receipts = HashMap::from([(receipt.transaction_hash, receipt)]);
+
+        let transactions = block_transactions(block, receipts).unwrap();
+        assert_eq!(transactions.len(), 2);
+        assert!(transactions.iter().all(|tx| tx.block_number == Some(100) && tx.timestamp == 0x6553f100));
+
+        assert_eq!(transactions[0].hash, receipted);
+        assert_eq!(transactions[0].status, Some(true));
+        assert_eq!(transactions[0].gas_used, Some(21000));
+
+        // A receipt missing from the node leaves the transaction mined but without outcome
+        assert_eq!(transactions[1].hash, unreceipted);
+        assert_eq!(transactions[1].status, None);
+        assert_eq!(transactions[1].gas_used, None);
+    }
+
+    #[test]
+    fn test_transport_from_url() {
+        assert_eq!(TransportKind::from_url("https://mainnet.base.org"), TransportKind::Http);
+        assert_eq!(TransportKind::from_url("HTTP://localhost:8545"), TransportKind::Http);
+        assert_eq!(TransportKind::from_url("wss://base-rpc.publicnode.com"), TransportKind::WebSocket);
+        assert_eq!(TransportKind::from_url("ws://localhost:8546"), TransportKind::WebSocket);
+    }
 
-    if wei_u128 == 0 {
-        return "0.0000".to_string();
+    #[test]
+    fn test_catch_up_range() {
+        assert_eq!(catch_up_range(99, 100), 100..=100);
+        assert_eq!(catch_up_range(100, 103), 101..=103);
+        assert!(catch_up_range(100, 100).is_empty()); // No new block
+        assert!(catch_up_range(100, 98).is_empty()); // Head behind after a reorg or node switch
+        assert_eq!(catch_up_range(100, 200), 193..=200); // Long gap skips ahead
+        assert_eq!(catch_up_range(0, 3), 1..=3);
     }
 
-    let ether = wei_u128 / WEI_PER_ETHER;
-    let remainder = wei_u128 % WEI_PER_ETHER;
+    #[test]
+    fn test_pending_item_routing() {
+        let hash = "0x88df016429689c079f3b2f6ad39fa052532c56795b733da78a91ebe6a713944b";
+        match PendingItem::from_value(serde_json::json!(hash)) {
+            Some(PendingItem::Hash(routed)) => assert_eq!(format!("{:#x}", routed), hash),
+            other => panic!("expected a hash, got {:?}", other),
+        }
 
-    // Get first 6 decimal places for better precision
-    let decimal_part = (remainder * 1_000_000) / WEI_PER_ETHER;
+        match PendingItem::from_value(legacy_tx_json()) {
+            Some(PendingItem::Full(tx)) => assert_eq!(format!("{:#x}", tx.hash), hash),
+            other => panic!("expected a full transaction, got {:?}", other),
+        }
 
-    // Format with appropriate precision
-    if ether > 0 {
-        format!("{}.{:04}", ether, decimal_part / 100) // Show 4 decimals for large values
-    } else {
-        format!("0.{:06}", decimal_part) // Show 6 decimals for small values
+        assert!(PendingItem::from_value(serde_json::json!("not a hash")).is_none());
+        assert!(PendingItem::from_value(serde_json::json!({ "hash": hash })).is_none());
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,230 @@
+use std::time::{Duration, Instant};
+
+/// Latency assumed for an endpoint that has never connected
+const UNKNOWN_LATENCY_MS: f64 = 500.0;
+
+/// Seconds without a transaction after which an endpoint counts as fully stale
+const MAX_STALENESS_SECS: f64 = 60.0;
+
+/// Weight of the newest sample in the latency moving average
+const LATENCY_SMOOTHING: f64 = 0.3;
+
+/// The endpoint the connection task is currently using, for display
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ActiveEndpoint {
+    pub name: String,
+    pub url: String,
+    pub index: usize,
+    pub total: usize,
+}
+
+/// An RPC endpoint and its observed health
+#[derive(Debug)]
+struct Endpoint {
+    url: String,
+    name: String,
+    successes: u32,
+    failures: u32,
+    latency_ms: Option<f64>,
+    last_tx: Option<Instant>,
+    /// Failed since the last successful connection to any endpoint
+    tried: bool,
+}
+
+impl Endpoint {
+    /// Lower is healthier: error rate dominates, then latency, then time since the last transaction
+    fn score(&self, now: Instant) -> f64 {
+        let attempts = self.successes + self.failures;
+        let error_rate = if attempts == 0 {
+            0.0
+        } else {
+            self.failures as f64 / attempts as f64
+        };
+        let latency = self.latency_ms.unwrap_or(UNKNOWN_LATENCY_MS);
+        let staleness = self
+            .last_tx
+            .map(|seen| now.duration_since(seen).as_secs_f64())
+            .unwrap_or(MAX_STALENESS_SECS)
+            .min(MAX_STALENESS_SECS);
+
+        error_rate * 10_000.0 + latency + staleness * 10.0
+    }
+}
+
+/// Primary and fallback endpoints with health scoring for failover
+#[derive(Debug)]
+pub struct EndpointSet {
+    endpoints: Vec<Endpoint>,
+    active: usize,
+}
+
+impl EndpointSet {
+    /// The first URL is the primary and is used first
+    pub fn new(urls: &[String]) -> Self {
+        let endpoints = urls
+            .iter()
+            .map(|url| Endpoint {
+                url: url.clone(),
+                name: format_rpc_url(url),
+                successes: 0,
+                failures: 0,
+                latency_ms: None,
+                last_tx: None,
+                tried: false,
+            })
+            .collect();
+        Self { endpoints, active: 0 }
+    }
+
+    pub fn active_url(&self) -> &str {
+        &self.endpoints[self.active].url
+    }
+
+    pub fn active(&self) -> ActiveEndpoint {
+        let endpoint = &self.endpoints[self.active];
+        ActiveEndpoint {
+            name: endpoint.name.clone(),
+            url: endpoint.url.clone(),
+            index: self.active,
+            total: self.endpoints.len(),
+        }
+    }
+
+    /// The active endpoint connected; every endpoint becomes eligible for failover again
+    pub fn record_connected(&mut self, latency: Duration) {
+        let endpoint = &mut self.endpoints[self.active];
+        let sample = latency.as_secs_f64() * 1000.0;
+        endpoint.latency_ms = Some(match endpoint.latency_ms {
+            Some(avg) => avg + LATENCY_SMOOTHING * (sample - avg),
+            None => sample,
+        });
+        endpoint.successes += 1;
+
+        for endpoint in &mut self.endpoints {
+            endpoint.tried = false;
+        }
+    }
+
+    pub fn record_transaction(&mut self) {
+        self.endpoints[self.active].last_tx = Some(Instant::now());
+    }
+
+    pub fn record_failure(&mut self) {
+        let endpoint = &mut self.endpoints[self.active];
+        endpoint.failures += 1;
+        endpoint.tried = true;
+    }
+
+    /// Switch to the healthiest endpoint that hasn't failed since the last successful
+    /// connection. Returns false once every endpoint has failed.
+    pub fn failover(&mut self) -> bool {
+        match self.best(|endpoint| !endpoint.tried) {
+            Some(index) => {
+                self.active = index;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Start a new round over all endpoints, beginning with the healthiest
+    pub fn restart_round(&mut self) {
+        for endpoint in &mut self.endpoints {
+            endpoint.tried = false;
+        }
+        if let Some(index) = self.best(|_| true) {
+            self.active = index;
+        }
+    }
+
+    /// Lowest-scoring eligible endpoint; earlier entries win ties
+    fn best(&self, eligible: impl Fn(&Endpoint) -> bool) -> Option<usize> {
+        let now = Instant::now();
+        self.endpoints
+            .iter()
+            .enumerate()
+            .filter(|(_, endpoint)| eligible(endpoint))
+            .min_by(|(_, a), (_, b)| a.score(now).total_cmp(&b.score(now)))
+            .map(|(index, _)| index)
+    }
+}
+
+/// Short display name for an RPC URL
+pub fn format_rpc_url(url: &str) -> String {
+    // Extract the meaningful part of the URL
+    // Remove protocol (ws://, wss://, http://, https://)
+    let without_protocol = url
+        .strip_prefix("wss://")
+        .or_else(|| url.strip_prefix("ws://"))
+        .or_else(|| url.strip_prefix("https://"))
+        .or_else(|| url.strip_prefix("http://"))
+        .unwrap_or(url);
+
+    // If it's a known provider, simplify it
+    if without_protocol.contains("base-rpc.publicnode.com") {
+        "Base PublicNode".to_string()
+    } else if without_protocol.contains("base-mainnet") {
+        "Base Mainnet".to_string()
+    } else if without_protocol.contains("base-sepolia") || without_protocol.contains("base-testnet") {
+        "Base Sepolia".to_string()
+    } else if without_protocol.contains("mainnet.infura.io") {
+        "Ethereum Mainnet (Infura)".to_string()
+    } else if without_protocol.contains("polygon-rpc.com") {
+        "Polygon".to_string()
+    } else if without_protocol.contains("arb1.arbitrum.io") {
+        "Arbitrum One".to_string()
+    } else if without_protocol.contains("optimism.io") {
+        "Optimism".to_string()
+    } else if without_protocol.contains("localhost") || without_protocol.contains("127.0.0.1") {
+        "Local Node".to_string()
+    } else {
+        // For other URLs, just show the domain
+        without_protocol
+            .split('/')
+            .next()
+            .unwrap_or(without_protocol)
+            .to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_rpc_url() {
+        assert_eq!(format_rpc_url("wss://base-rpc.publicnode.com"), "Base PublicNode");
+        assert_eq!(format_rpc_url("https://base-mainnet.g.alchemy.com/v2/abc"), "Base Mainnet");
+        assert_eq!(format_rpc_url("ws://localhost:8545"), "Local Node");
+        assert_eq!(format_rpc_url("wss://custom.provider.com/rpc"), "custom.provider.com");
+        assert_eq!(format_rpc_url("http://127.0.0.1:8545"), "Local Node");
+        assert_eq!(format_rpc_url("wss://mainnet.infura.io/ws/v3/key"), "Ethereum Mainnet (Infura)");
+    }
+
+    #[test]
+    fn test_failover_prefers_healthy_untried_endpoints() {
+        let urls = ["wss://a.example", "wss://b.example", "wss://c.example"].map(String::from);
+        let mut endpoints = EndpointSet::new(&urls);
+
+        // c has been slow before, b has a clean record
+        endpoints.active = 2;
+        endpoints.record_connected(Duration::from_secs(2));
+        endpoints.active = 1;
+        endpoints.record_connected(Duration::from_millis(50));
+        endpoints.active = 0;
+
+        endpoints.record_failure();
+        assert!(endpoints.failover());
+        assert_eq!(endpoints.active().name, "b.example");
+
+        endpoints.record_failure();
+        assert!(endpoints.failover());
+        assert_eq!(endpoints.active().name, "c.example");
+
+        endpoints.record_failure();
+        assert!(!endpoints.failover());
+
+        endpoints.restart_round();
+        assert_eq!(endpoints.active().index, 1);
+    }
+}
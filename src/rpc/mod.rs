@@ -1,4 +1,16 @@
 pub mod client;
+pub mod endpoints;
+pub mod pipeline;
+pub mod reconnect;
+pub mod tracker;
+pub mod watchdog;
 
-#[allow(unused_imports)]
-pub use client::RpcClient;
\ No newline at end of file
+pub use client::{
+    current_client, enhance_with_receipt, MinedBlock, PendingTxMode, RpcClient, RpcCommand, SharedClient, SourceMode,
+    DEFAULT_POLL_INTERVAL, MIN_POLL_INTERVAL,
+};
+pub use endpoints::{ActiveEndpoint, EndpointSet};
+pub use pipeline::{FetchCounters, FetchOptions, FetchSnapshot};
+pub use reconnect::ReconnectPolicy;
+pub use tracker::{spawn_lifecycle_tracker, ChainEvent};
+pub use watchdog::{StallVerdict, StallWatchdog};
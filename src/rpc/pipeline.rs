@@ -0,0 +1,207 @@
+use alloy::primitives::TxHash;
+use alloy::providers::{Provider, RootProvider};
+use alloy::rpc::types::Transaction as AlloyTransaction;
+use alloy::transports::BoxTransport;
+use std::collections::{HashSet, VecDeque};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use tokio::sync::{mpsc, Semaphore};
+
+use super::client::{parse_transaction, ConnectionHealth};
+
+/// Number of recent hashes remembered for de-duplication
+const SEEN_CAPACITY: usize = 20_000;
+
+/// Tuning for the pending transaction fetch stage
+#[derive(Debug, Clone, Copy)]
+pub struct FetchOptions {
+    /// Maximum number of `eth_getTransactionByHash` requests in flight
+    pub concurrency: usize,
+    /// Hashes waiting for a fetch slot; new hashes are dropped once it is full
+    pub queue_size: usize,
+}
+
+/// Live counters of the fetch stage, shared with the UI across reconnects
+#[derive(Debug, Default)]
+pub struct FetchCounters {
+    queued: AtomicU64,
+    in_flight: AtomicU64,
+    dropped: AtomicU64,
+    duplicates: AtomicU64,
+    pushed: AtomicU64,
+}
+
+/// Point-in-time copy of the fetch counters for display
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct FetchSnapshot {
+    pub queued: u64,
+    pub in_flight: u64,
+    pub dropped: u64,
+    pub duplicates: u64,
+    /// Full transactions pushed by the node, which skip the fetch
+    pub pushed: u64,
+}
+
+impl FetchCounters {
+    pub fn snapshot(&self) -> FetchSnapshot {
+        FetchSnapshot {
+            queued: self.queued.load(Ordering::Relaxed),
+            in_flight: self.in_flight.load(Ordering::Relaxed),
+            dropped: self.dropped.load(Ordering::Relaxed),
+            duplicates: self.duplicates.load(Ordering::Relaxed),
+            pushed: self.pushed.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// Bounded, concurrent fetch stage between the hash subscription and the UI.
+///
+/// Hashes are de-duplicated, then queued for a fixed pool of fetch slots. When the
+/// queue is full the hash is dropped and counted rather than stalling the subscription.
+pub struct FetchPipeline {
+    queue: mpsc::Sender<TxHash>,
+    out: mpsc::Sender<crate::model::Transaction>,
+    seen: SeenHashes,
+    counters: Arc<FetchCounters>,
+}
+
+impl FetchPipeline {
+    /// Start the fetch workers; fetched transactions are delivered on the returned receiver
+    pub fn spawn(
+        provider: RootProvider<BoxTransport>,
+        health: Arc<ConnectionHealth>,
+        options: FetchOptions,
+        counters: Arc<FetchCounters>,
+    ) -> (Self, mpsc::Receiver<crate::model::Transaction>) {
+        let queue_size = options.queue_size.max(1);
+        let (queue, mut queue_rx) = mpsc::channel::<TxHash>(queue_size);
+        let (out, out_rx) = mpsc::channel(queue_size);
+
+        let slots = Arc::new(Semaphore::new(options.concurrency.max(1)));
+        let worker_counters = counters.clone();
+        let worker_out = out.clone();
+        tokio::spawn(async move {
+            loop {
+                // Wait for a free slot first so waiting hashes stay counted as queued
+                let Ok(permit) = slots.clone().acquire_owned().await else {
+                    break;
+                };
+                let Some(hash) = queue_rx.recv().await else {
+                    break; // Subscription ended and the queue is drained
+                };
+                worker_counters.queued.fetch_sub(1, Ordering::Relaxed);
+                worker_counters.in_flight.fetch_add(1, Ordering::Relaxed);
+
+                let provider = provider.clone();
+                let health = health.clone();
+                let out = worker_out.clone();
+                let counters = worker_counters.clone();
+                tokio::spawn(async move {
+                    match provider.get_transaction_by_hash(hash).await {
+                        Ok(tx_data) => {
+                            health.record_success();
+                            if let Some(parsed) = tx_data.and_then(|tx| parse_transaction(tx).ok()) {
+                                let _ = out.send(parsed).await;
+                            }
+                        }
+                        Err(_) => health.record_failure(),
+                    }
+                    counters.in_flight.fetch_sub(1, Ordering::Relaxed);
+                    drop(permit);
+                });
+            }
+        });
+
+        let pipeline = Self {
+            queue,
+            out,
+            seen: SeenHashes::new(SEEN_CAPACITY),
+            counters,
+        };
+        (pipeline, out_rx)
+    }
+
+    /// Sender side of the output channel, e.g. to watch for the receiver closing
+    pub fn output(&self) -> mpsc::Sender<crate::model::Transaction> {
+        self.out.clone()
+    }
+
+    /// Offer a pending hash to the pipeline without waiting
+    pub fn submit(&mut self, hash: TxHash) {
+        if !self.seen.insert(hash) {
+            self.counters.duplicates.fetch_add(1, Ordering::Relaxed);
+            return;
+        }
+
+        // Count before sending so a fast worker never sees the gauge underflow
+        self.counters.queued.fetch_add(1, Ordering::Relaxed);
+        if self.queue.try_send(hash).is_err() {
+            self.counters.queued.fetch_sub(1, Ordering::Relaxed);
+            self.counters.dropped.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    /// Pass on a full transaction pushed by the node, skipping the fetch
+    pub fn deliver(&mut self, tx: AlloyTransaction) {
+        if !self.seen.insert(tx.hash) {
+            self.counters.duplicates.fetch_add(1, Ordering::Relaxed);
+            return;
+        }
+
+        self.counters.pushed.fetch_add(1, Ordering::Relaxed);
+        if let Ok(parsed) = parse_transaction(tx) {
+            if self.out.try_send(parsed).is_err() {
+                self.counters.dropped.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+    }
+}
+
+/// Fixed-size memory of recently seen hashes, evicting the oldest first
+struct SeenHashes {
+    set: HashSet<TxHash>,
+    order: VecDeque<TxHash>,
+    capacity: usize,
+}
+
+impl SeenHashes {
+    fn new(capacity: usize) -> Self {
+        Self {
+            set: HashSet::with_capacity(capacity),
+            order: VecDeque::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    /// Record a hash, returning false if it was already seen
+    fn insert(&mut self, hash: TxHash) -> bool {
+        if !self.set.insert(hash) {
+            return false;
+        }
+        self.order.push_back(hash);
+        if self.order.len() > self.capacity {
+            if let Some(oldest) = self.order.pop_front() {
+                self.set.remove(&oldest);
+            }
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_seen_hashes_evicts_oldest() {
+        let mut seen = SeenHashes::new(2);
+        let (a, b, c) = (TxHash::with_last_byte(1), TxHash::with_last_byte(2), TxHash::with_last_byte(3));
+
+        assert!(seen.insert(a));
+        assert!(!seen.insert(a));
+        assert!(seen.insert(b));
+        assert!(seen.insert(c)); // Evicts a
+        assert!(!seen.insert(b));
+        assert!(seen.insert(a));
+    }
+}
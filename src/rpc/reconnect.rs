@@ -0,0 +1,75 @@
+use rand::Rng;
+use std::time::Duration;
+
+/// Longest wait between two reconnection attempts
+const MAX_DELAY: Duration = Duration::from_secs(60);
+
+/// Exponential backoff with jitter for reconnecting to the RPC endpoint
+#[derive(Debug, Clone, Copy)]
+pub struct ReconnectPolicy {
+    base_delay: Duration,
+    max_delay: Duration,
+    max_attempts: u32,
+}
+
+impl ReconnectPolicy {
+    /// `max_attempts` of 0 retries forever
+    pub fn new(max_attempts: u32, base_delay: Duration) -> Self {
+        Self {
+            base_delay,
+            max_delay: MAX_DELAY.max(base_delay),
+            max_attempts,
+        }
+    }
+
+    pub fn max_attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    /// Whether the 1-based `attempt` is past the allowed number of attempts
+    pub fn gives_up(&self, attempt: u32) -> bool {
+        self.max_attempts != 0 && attempt > self.max_attempts
+    }
+
+    /// Wait before the 1-based `attempt`: the base delay doubled per attempt and capped,
+    /// with the upper half randomised so clients don't retry in lockstep
+    pub fn delay(&self, attempt: u32) -> Duration {
+        let factor = 1u32 << attempt.saturating_sub(1).min(16);
+        let backoff = self.base_delay.saturating_mul(factor).min(self.max_delay);
+
+        let half = backoff / 2;
+        let jitter = rand::thread_rng().gen_range(0..=half.as_millis() as u64);
+        half + Duration::from_millis(jitter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_delay_grows_and_is_capped() {
+        let policy = ReconnectPolicy::new(10, Duration::from_secs(1));
+
+        for _ in 0..20 {
+            let first = policy.delay(1);
+            assert!(first >= Duration::from_millis(500) && first <= Duration::from_secs(1));
+
+            let third = policy.delay(3);
+            assert!(third >= Duration::from_secs(2) && third <= Duration::from_secs(4));
+
+            let late = policy.delay(30);
+            assert!(late >= MAX_DELAY / 2 && late <= MAX_DELAY);
+        }
+    }
+
+    #[test]
+    fn test_gives_up_after_max_attempts() {
+        let policy = ReconnectPolicy::new(3, Duration::from_secs(1));
+        assert!(!policy.gives_up(3));
+        assert!(policy.gives_up(4));
+
+        let forever = ReconnectPolicy::new(0, Duration::from_secs(1));
+        assert!(!forever.gives_up(u32::MAX));
+    }
+}
@@ -0,0 +1,66 @@
+use std::time::Duration;
+use tokio::sync::mpsc;
+
+use super::client::{catch_up_range, current_client, MinedBlock, SharedClient, MIN_POLL_INTERVAL};
+
+/// What the lifecycle tracker reports about the chain
+#[derive(Debug, Clone)]
+pub enum ChainEvent {
+    /// A new block, with the hashes of its transactions
+    BlockMined(MinedBlock),
+    /// Latest finalized block number
+    Finalized(u64),
+}
+
+/// Follow new blocks on whichever connection is current, so buffered pending
+/// transactions move on to mined and finalized. Runs until `events` is closed.
+pub fn spawn_lifecycle_tracker<E>(shared_client: SharedClient, poll_interval: Duration, events: mpsc::UnboundedSender<E>)
+where
+    E: From<ChainEvent> + Send + 'static,
+{
+    tokio::spawn(async move {
+        // Also runs in WebSocket mode, so don't rely on the HTTP poll interval being validated
+        let mut ticker = tokio::time::interval(poll_interval.max(MIN_POLL_INTERVAL));
+        let mut last_block: Option<u64> = None;
+        let mut finalized: Option<u64> = None;
+
+        while !events.is_closed() {
+            ticker.tick().await;
+            let Some(client) = current_client(&shared_client) else {
+                continue;
+            };
+            let head = match client.block_number().await {
+                Ok(head) => {
+                    client.health().record_success();
+                    head
+                }
+                Err(_) => {
+                    client.health().record_failure();
+                    continue;
+                }
+            };
+
+            // Start at the current head
+            let previous = last_block;
+            for number in catch_up_range(last_block.unwrap_or(head.saturating_sub(1)), head) {
+                let Ok(block) = client.fetch_mined_block(number).await else {
+                    client.health().record_failure();
+                    break; // Retry from this block on the next tick
+                };
+                let _ = events.send(ChainEvent::BlockMined(block).into());
+                last_block = Some(number);
+            }
+
+            // Finality only moves with new blocks. Not every node tracks it.
+            if last_block == previous {
+                continue;
+            }
+            if let Ok(number) = client.finalized_block_number().await {
+                if finalized != Some(number) {
+                    finalized = Some(number);
+                    let _ = events.send(ChainEvent::Finalized(number).into());
+                }
+            }
+        }
+    });
+}
@@ -0,0 +1,82 @@
+use std::time::{Duration, Instant};
+
+/// Bounds on how often the watchdog pings the node
+const MIN_PING_INTERVAL: Duration = Duration::from_secs(1);
+const MAX_PING_INTERVAL: Duration = Duration::from_secs(10);
+
+/// What the watchdog concluded on a check
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StallVerdict {
+    Healthy,
+    /// Silent for half the timeout; reported once per stall
+    Stalled { since: Instant },
+    /// Silent for the whole timeout; the connection should be replaced
+    Reconnect { idle: Duration },
+}
+
+/// Detects subscriptions that stay open but stop delivering transactions
+#[derive(Debug)]
+pub struct StallWatchdog {
+    timeout: Duration,
+    last_activity: Instant,
+    stalled: bool,
+}
+
+impl StallWatchdog {
+    pub fn new(timeout: Duration) -> Self {
+        Self {
+            timeout,
+            last_activity: Instant::now(),
+            stalled: false,
+        }
+    }
+
+    /// How often to ping the node while waiting for transactions
+    pub fn ping_interval(&self) -> Duration {
+        (self.timeout / 4).clamp(MIN_PING_INTERVAL, MAX_PING_INTERVAL)
+    }
+
+    /// A transaction arrived. Returns true if this ends a stall.
+    pub fn record_activity(&mut self) -> bool {
+        self.last_activity = Instant::now();
+        std::mem::take(&mut self.stalled)
+    }
+
+    pub fn check(&mut self, now: Instant) -> StallVerdict {
+        let idle = now.saturating_duration_since(self.last_activity);
+        if idle >= self.timeout {
+            StallVerdict::Reconnect { idle }
+        } else if idle >= self.timeout / 2 && !self.stalled {
+            self.stalled = true;
+            StallVerdict::Stalled { since: self.last_activity }
+        } else {
+            StallVerdict::Healthy
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stall_then_reconnect() {
+        let mut watchdog = StallWatchdog::new(Duration::from_secs(30));
+        let start = watchdog.last_activity;
+
+        assert_eq!(watchdog.check(start + Duration::from_secs(5)), StallVerdict::Healthy);
+        assert_eq!(
+            watchdog.check(start + Duration::from_secs(16)),
+            StallVerdict::Stalled { since: start }
+        );
+        // Reported once
+        assert_eq!(watchdog.check(start + Duration::from_secs(20)), StallVerdict::Healthy);
+        assert_eq!(
+            watchdog.check(start + Duration::from_secs(31)),
+            StallVerdict::Reconnect { idle: Duration::from_secs(31) }
+        );
+
+        assert!(watchdog.record_activity());
+        assert!(!watchdog.record_activity());
+    }
+}
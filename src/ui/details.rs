@@ -1,21 +1,89 @@
-use crate::model::Transaction;
+use crate::app::AppState;
+use crate::model::units::Denomination;
+use crate::model::{DecodedLog, DecodedParam, Lifecycle, Transaction};
+use alloy::primitives::U256;
 use ratatui::prelude::*;
 use ratatui::widgets::{Block, Borders, Clear, List, ListItem};
 
-pub fn render_transaction_details(frame: &mut Frame, tx: &Transaction, scroll_offset: usize) {
+pub fn render_transaction_details(frame: &mut Frame, tx: &Transaction, state: &AppState, scroll_offset: usize) {
     let area = centered_rect(90, 80, frame.area());
 
     // Clear the background
     frame.render_widget(Clear, area);
 
-    // Create details text as list items
-    let mut details: Vec<ListItem> = vec![];
+    let mut details = if state.show_raw_json {
+        raw_json_items(state.selected_raw_json.as_deref())
+    } else {
+        detail_items(tx, state)
+    };
 
+    // Add footer instructions before calculating scroll
     details.push(ListItem::new(Line::from("")));
+    details.push(ListItem::new(Line::from(vec![
+        Span::styled(
+            "Press ESC, Enter, or Q to close | ↑/↓ to scroll | R: raw JSON",
+            Style::default().fg(Color::Gray).italic(),
+        ),
+    ])));
+
+    // Add scroll indicator and instructions
+    let total_lines = details.len();
+    let visible_height = area.height.saturating_sub(2) as usize; // Subtract 2 for borders
+
+    // Calculate max scroll offset
+    let max_scroll = total_lines.saturating_sub(visible_height);
+    let adjusted_scroll = scroll_offset.min(max_scroll);
+
+    // Add scroll indicator to title if content is scrollable
+    let name = if state.show_raw_json { "Raw Transaction" } else { "Transaction Details" };
+    let title = if total_lines > visible_height {
+        format!(" {} (Line {}/{}) ",
+                name,
+                adjusted_scroll + 1,
+                total_lines - adjusted_scroll.min(visible_height))
+    } else {
+        format!(" {} ", name)
+    };
+
+    // Get visible items based on scroll offset
+    let visible_items: Vec<ListItem> = details
+        .into_iter()
+        .skip(adjusted_scroll)
+        .take(visible_height)
+        .collect();
+
+    let list = List::new(visible_items)
+        .block(
+            Block::default()
+                .title(title)
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Cyan))
+                .border_type(ratatui::widgets::BorderType::Rounded),
+        )
+        .style(Style::default().bg(Color::Black));
+
+    frame.render_widget(list, area);
+}
 
+/// Decoded fields of the transaction, one list item per line
+fn detail_items<'a>(tx: &'a Transaction, state: &AppState) -> Vec<ListItem<'a>> {
+    let chain = state.stats.chain();
+    let mut details: Vec<ListItem> = vec![
+        ListItem::new(Line::from("")),
+        ListItem::new(Line::from(vec![
+            Span::styled("Hash: ", Style::default().fg(Color::Yellow).bold()),
+            Span::raw(&tx.hash),
+        ])),
+    ];
+    if let Some(link) = chain.explorer_link(&tx.hash) {
+        details.push(ListItem::new(Line::from(vec![
+            Span::styled("Explorer: ", Style::default().fg(Color::Yellow).bold()),
+            Span::styled(link, Style::default().fg(Color::Blue).underlined()),
+        ])));
+    }
     details.push(ListItem::new(Line::from(vec![
-        Span::styled("Hash: ", Style::default().fg(Color::Yellow).bold()),
-        Span::raw(&tx.hash),
+        Span::styled("Chain: ", Style::default().fg(Color::Yellow).bold()),
+        Span::raw(format!("{} ({})", chain.name, chain.id)),
     ])));
     details.push(ListItem::new(Line::from("")));
 
@@ -23,14 +91,22 @@ pub fn render_transaction_details(frame: &mut Frame, tx: &Transaction, scroll_of
         Span::styled("From: ", Style::default().fg(Color::Yellow).bold()),
         Span::raw(&tx.from),
     ])));
+    details.push(ListItem::new(Line::from(vec![
+        Span::styled("Nonce: ", Style::default().fg(Color::Yellow).bold()),
+        Span::raw(tx.nonce.to_string()),
+    ])));
     details.push(ListItem::new(Line::from("")));
 
     // Add 'To' field
     if let Some(to) = &tx.to {
-        details.push(ListItem::new(Line::from(vec![
+        let mut spans = vec![
             Span::styled("To: ", Style::default().fg(Color::Yellow).bold()),
             Span::raw(to),
-        ])));
+        ];
+        if let Some(label) = &tx.to_label {
+            spans.push(Span::styled(format!(" ({})", label), Style::default().fg(Color::Cyan)));
+        }
+        details.push(ListItem::new(Line::from(spans)));
     } else {
         details.push(ListItem::new(Line::from(vec![
             Span::styled("To: ", Style::default().fg(Color::Yellow).bold()),
@@ -43,7 +119,11 @@ pub fn render_transaction_details(frame: &mut Frame, tx: &Transaction, scroll_of
     details.push(ListItem::new(Line::from(vec![
         Span::styled("Value: ", Style::default().fg(Color::Yellow).bold()),
         Span::styled(
-            format!("{} ETH", tx.value),
+            format!(
+                "{} {}",
+                state.format_amount(tx.value, state.value_unit),
+                state.value_unit.label(&chain.symbol)
+            ),
             Style::default().fg(Color::Green),
         ),
     ])));
@@ -51,17 +131,57 @@ pub fn render_transaction_details(frame: &mut Frame, tx: &Transaction, scroll_of
 
     // Add function information
     if let Some(func_sig) = &tx.function_sig {
+        let (match_text, match_color) = if func_sig.guessed {
+            (" (guessed from selector)", Color::DarkGray)
+        } else {
+            (" (verified: bound contract ABI)", Color::Green)
+        };
         details.push(ListItem::new(Line::from(vec![
             Span::styled("Function: ", Style::default().fg(Color::Yellow).bold()),
             Span::styled(
                 &func_sig.name,
                 Style::default().fg(crate::model::decoder::get_function_color(&func_sig.name)),
             ),
+            Span::styled(match_text, Style::default().fg(match_color).italic()),
         ])));
+        if !func_sig.signature.is_empty() {
+            details.push(ListItem::new(Line::from(vec![
+                Span::styled("Signature: ", Style::default().fg(Color::Yellow).bold()),
+                Span::raw(&func_sig.signature),
+            ])));
+        }
         details.push(ListItem::new(Line::from(vec![
             Span::styled("Selector: ", Style::default().fg(Color::Yellow).bold()),
             Span::raw(&func_sig.selector),
         ])));
+
+        // Other signatures sharing this selector
+        if !func_sig.alternatives.is_empty() {
+            details.push(ListItem::new(Line::from(vec![
+                Span::styled("Alternatives: ", Style::default().fg(Color::Yellow).bold()),
+                Span::styled(
+                    format!("{} other signature(s) share this selector", func_sig.alternatives.len()),
+                    Style::default().fg(Color::DarkGray).italic(),
+                ),
+            ])));
+            for alternative in &func_sig.alternatives {
+                details.push(ListItem::new(Line::from(vec![
+                    Span::styled("  • ", Style::default().fg(Color::DarkGray)),
+                    Span::styled(alternative, Style::default().fg(Color::Gray)),
+                ])));
+            }
+        }
+
+        // Render decoded arguments as a tree
+        if !func_sig.args.is_empty() {
+            details.push(ListItem::new(Line::from(vec![
+                Span::styled("Arguments: ", Style::default().fg(Color::Yellow).bold()),
+            ])));
+            let count = func_sig.args.len();
+            for (i, arg) in func_sig.args.iter().enumerate() {
+                push_param_tree(&mut details, arg, "", i + 1 == count);
+            }
+        }
     } else {
         details.push(ListItem::new(Line::from(vec![
             Span::styled("Function: ", Style::default().fg(Color::Yellow).bold()),
@@ -99,6 +219,42 @@ pub fn render_transaction_details(frame: &mut Frame, tx: &Transaction, scroll_of
     }
     details.push(ListItem::new(Line::from("")));
 
+    // Lifecycle as followed by the block tracker
+    let lifecycle = tx.lifecycle(state.stats.finalized_block);
+    let mut lifecycle_spans = vec![
+        Span::styled("Lifecycle: ", Style::default().fg(Color::Yellow).bold()),
+        Span::styled(lifecycle.label(), Style::default().fg(lifecycle_color(lifecycle)).bold()),
+    ];
+    if let Some(latency) = tx.inclusion_latency_ms() {
+        lifecycle_spans.push(Span::raw(format!(
+            " (included {:.1}s after first seen)",
+            latency as f64 / 1000.0
+        )));
+    }
+    details.push(ListItem::new(Line::from(lifecycle_spans)));
+
+    // Speed-ups and cancellations, linked by sender and nonce
+    if let Some(replacement) = &tx.replaced_by {
+        details.push(ListItem::new(Line::from(vec![
+            Span::styled("Replaced By: ", Style::default().fg(Color::Yellow).bold()),
+            Span::styled(replacement.as_str(), Style::default().fg(Color::Magenta)),
+            Span::styled(" (l: open)", Style::default().fg(Color::DarkGray)),
+        ])));
+    }
+    if let Some(replaced) = &tx.replaces {
+        let mut spans = vec![
+            Span::styled("Replaces: ", Style::default().fg(Color::Yellow).bold()),
+            Span::styled(replaced.as_str(), Style::default().fg(Color::Magenta)),
+        ];
+        if let Some(bump) = tx.fee_bump_pct {
+            spans.push(Span::raw(format!(" ({:+.1}% fee)", bump)));
+        }
+        if tx.replaced_by.is_none() {
+            spans.push(Span::styled(" (l: open)", Style::default().fg(Color::DarkGray)));
+        }
+        details.push(ListItem::new(Line::from(spans)));
+    }
+
     // Add receipt data if available
     if let Some(block_num) = tx.block_number {
         details.push(ListItem::new(Line::from(vec![
@@ -120,45 +276,81 @@ pub fn render_transaction_details(frame: &mut Frame, tx: &Transaction, scroll_of
     }
     details.push(ListItem::new(Line::from("")));
 
+    push_type_section(&mut details, tx, state);
+
     // Add gas information
     details.push(ListItem::new(Line::from(vec![
         Span::styled("Gas Limit: ", Style::default().fg(Color::Yellow).bold()),
-        Span::raw(&tx.gas_limit),
+        Span::raw(tx.gas_limit.to_string()),
     ])));
 
-    if let Some(gas_used) = &tx.gas_used {
+    if let Some(gas_used) = tx.gas_used {
         details.push(ListItem::new(Line::from(vec![
             Span::styled("Gas Used: ", Style::default().fg(Color::Yellow).bold()),
-            Span::raw(gas_used),
+            Span::raw(gas_used.to_string()),
         ])));
     }
 
-    if let Some(gas_price) = &tx.gas_price {
+    if let Some(gas_price) = tx.gas_price {
         details.push(ListItem::new(Line::from(vec![
             Span::styled("Gas Price: ", Style::default().fg(Color::Yellow).bold()),
-            Span::raw(format!("{} Gwei", gas_price)),
+            Span::raw(format!("{} Gwei", state.format_amount(U256::from(gas_price), Denomination::Gwei))),
         ])));
     }
 
-    if let Some(effective_gas_price) = &tx.effective_gas_price {
+    if let Some(effective_gas_price) = tx.effective_gas_price {
         details.push(ListItem::new(Line::from(vec![
             Span::styled("Effective Gas Price: ", Style::default().fg(Color::Yellow).bold()),
-            Span::raw(format!("{} Gwei", effective_gas_price)),
+            Span::raw(format!("{} Gwei", state.format_amount(U256::from(effective_gas_price), Denomination::Gwei))),
         ])));
     }
 
     // Calculate transaction cost if we have gas used and effective price
-    if let (Some(gas_used), Some(price)) = (&tx.gas_used, &tx.effective_gas_price) {
-        if let (Ok(gas), Ok(price_val)) = (gas_used.parse::<u128>(), price.parse::<u128>()) {
-            let cost_wei = gas * price_val;
-            let cost_eth = cost_wei as f64 / 1_000_000_000_000_000_000.0;
+    if let Some(cost_wei) = tx.fee_wei() {
+        let cost = state.format_amount(cost_wei, Denomination::Native);
+        details.push(ListItem::new(Line::from(vec![
+            Span::styled("Transaction Cost: ", Style::default().fg(Color::Yellow).bold()),
+            Span::styled(format!("{} {}", cost, chain.symbol), Style::default().fg(Color::Magenta)),
+        ])));
+    }
+    details.push(ListItem::new(Line::from("")));
+
+    // Add decoded receipt logs
+    if !tx.logs.is_empty() {
+        details.push(ListItem::new(Line::from(vec![
+            Span::styled(format!("Logs ({}):", tx.logs.len()), Style::default().fg(Color::Yellow).bold()),
+        ])));
+        for (i, log) in tx.logs.iter().enumerate() {
+            push_log(&mut details, i, log);
+        }
+        details.push(ListItem::new(Line::from("")));
+    }
+
+    // Add race mode arrivals, first endpoint first
+    if !tx.seen_by.is_empty() {
+        details.push(ListItem::new(Line::from(vec![
+            Span::styled("Seen By: ", Style::default().fg(Color::Yellow).bold()),
+        ])));
+        for (i, sighting) in tx.seen_by.iter().enumerate() {
+            let delta = if i == 0 {
+                Span::styled("first", Style::default().fg(Color::Green))
+            } else {
+                Span::styled(
+                    format!("+{} ms", tx.arrival_delta_ms(sighting)),
+                    Style::default().fg(Color::Yellow),
+                )
+            };
             details.push(ListItem::new(Line::from(vec![
-                Span::styled("Transaction Cost: ", Style::default().fg(Color::Yellow).bold()),
-                Span::styled(format!("{:.6} ETH", cost_eth), Style::default().fg(Color::Magenta)),
+                Span::styled("  • ", Style::default().fg(Color::DarkGray)),
+                Span::styled(race_endpoint_name(state, sighting.endpoint), Style::default().fg(Color::Cyan)),
+                Span::raw(" "),
+                delta,
             ])));
         }
+        details.push(ListItem::new(Line::from("")));
     }
-    details.push(ListItem::new(Line::from("")));
+
+    push_signature_section(&mut details, tx);
 
     // Add timestamp
     details.push(ListItem::new(Line::from(vec![
@@ -167,50 +359,206 @@ pub fn render_transaction_details(frame: &mut Frame, tx: &Transaction, scroll_of
     ])));
     details.push(ListItem::new(Line::from("")));
 
-    // Add footer instructions before calculating scroll
-    details.push(ListItem::new(Line::from("")));
+    details
+}
+
+/// The transaction as the node returned it, pretty-printed
+fn raw_json_items(raw_json: Option<&str>) -> Vec<ListItem<'_>> {
+    match raw_json {
+        Some(json) => json
+            .lines()
+            .map(|line| ListItem::new(Line::from(line)))
+            .collect(),
+        None => vec![ListItem::new(Line::from(Span::styled(
+            "Raw JSON-RPC object not available for this transaction",
+            Style::default().fg(Color::DarkGray).italic(),
+        )))],
+    }
+}
+
+/// Append a receipt log: emitter and event name, then decoded params or raw topics/data
+fn push_log<'a>(details: &mut Vec<ListItem<'a>>, index: usize, log: &'a DecodedLog) {
+    let mut header = vec![
+        Span::styled(format!("  #{} ", index), Style::default().fg(Color::DarkGray)),
+        match &log.name {
+            Some(name) => Span::styled(name, Style::default().fg(Color::Cyan).bold()),
+            None => Span::styled("Unknown event", Style::default().fg(Color::Gray).italic()),
+        },
+        Span::styled(" @ ", Style::default().fg(Color::DarkGray)),
+        Span::raw(&log.address),
+    ];
+    if let Some(label) = crate::model::decoder::contract_label(&log.address) {
+        header.push(Span::styled(format!(" ({})", label), Style::default().fg(Color::Cyan)));
+    }
+    details.push(ListItem::new(Line::from(header)));
+
+    if log.name.is_some() {
+        let count = log.params.len();
+        for (i, param) in log.params.iter().enumerate() {
+            push_param_tree(details, param, "   ", i + 1 == count);
+        }
+    } else {
+        for (i, topic) in log.topics.iter().enumerate() {
+            details.push(ListItem::new(Line::from(vec![
+                Span::styled(format!("     topic{}: ", i), Style::default().fg(Color::DarkGray)),
+                Span::raw(topic),
+            ])));
+        }
+        if log.data.len() > 2 {
+            details.push(ListItem::new(Line::from(vec![
+                Span::styled("     data: ", Style::default().fg(Color::DarkGray)),
+                Span::raw(&log.data),
+            ])));
+        }
+    }
+}
+
+/// Append a decoded parameter and its children using tree connectors
+fn push_param_tree<'a>(details: &mut Vec<ListItem<'a>>, param: &'a DecodedParam, prefix: &str, is_last: bool) {
+    let connector = if is_last { "└─ " } else { "├─ " };
+
+    let mut spans = vec![
+        Span::styled(format!("  {}{}", prefix, connector), Style::default().fg(Color::DarkGray)),
+        Span::styled(&param.name, Style::default().fg(Color::Cyan)),
+        Span::styled(format!(" ({})", param.ty), Style::default().fg(Color::DarkGray)),
+    ];
+    if param.children.is_empty() {
+        spans.push(Span::raw(": "));
+        spans.push(Span::styled(&param.value, Style::default().fg(Color::White)));
+    }
+    details.push(ListItem::new(Line::from(spans)));
+
+    let child_prefix = format!("{}{}", prefix, if is_last { "   " } else { "│  " });
+    let count = param.children.len();
+    for (i, child) in param.children.iter().enumerate() {
+        push_param_tree(details, child, &child_prefix, i + 1 == count);
+    }
+}
+
+/// Transaction type with its fee caps, access list and blobs
+fn push_type_section<'a>(details: &mut Vec<ListItem<'a>>, tx: &'a Transaction, state: &AppState) {
+    let gwei = |wei: u128| format!("{} Gwei", state.format_amount(U256::from(wei), Denomination::Gwei));
+
     details.push(ListItem::new(Line::from(vec![
-        Span::styled(
-            "Press ESC, Enter, or Q to close | ↑/↓ to scroll",
-            Style::default().fg(Color::Gray).italic(),
-        ),
+        Span::styled("Type: ", Style::default().fg(Color::Yellow).bold()),
+        Span::styled(tx.type_description(), Style::default().fg(type_color(tx.tx_type))),
     ])));
+    let fee_caps = [
+        ("Max Fee: ", tx.max_fee_per_gas),
+        ("Max Priority Fee: ", tx.max_priority_fee_per_gas),
+        ("Max Blob Fee: ", tx.max_fee_per_blob_gas),
+    ];
+    for (label, fee) in fee_caps {
+        if let Some(fee) = fee {
+            details.push(ListItem::new(Line::from(vec![
+                Span::styled(label, Style::default().fg(Color::Yellow).bold()),
+                Span::raw(gwei(fee)),
+            ])));
+        }
+    }
 
-    // Add scroll indicator and instructions
-    let total_lines = details.len();
-    let visible_height = area.height.saturating_sub(2) as usize; // Subtract 2 for borders
+    if !tx.blob_versioned_hashes.is_empty() {
+        details.push(ListItem::new(Line::from(vec![
+            Span::styled(
+                format!("Blobs ({}):", tx.blob_versioned_hashes.len()),
+                Style::default().fg(Color::Yellow).bold(),
+            ),
+        ])));
+        for hash in &tx.blob_versioned_hashes {
+            details.push(ListItem::new(Line::from(vec![
+                Span::styled("  • ", Style::default().fg(Color::DarkGray)),
+                Span::raw(hash.as_str()),
+            ])));
+        }
+    }
 
-    // Calculate max scroll offset
-    let max_scroll = total_lines.saturating_sub(visible_height);
-    let adjusted_scroll = scroll_offset.min(max_scroll);
+    if !tx.access_list.is_empty() {
+        let keys: usize = tx.access_list.iter().map(|entry| entry.storage_keys.len()).sum();
+        details.push(ListItem::new(Line::from(vec![
+            Span::styled(
+                format!("Access List ({} addresses, {} slots):", tx.access_list.len(), keys),
+                Style::default().fg(Color::Yellow).bold(),
+            ),
+        ])));
+        for entry in &tx.access_list {
+            details.push(ListItem::new(Line::from(vec![
+                Span::styled("  • ", Style::default().fg(Color::DarkGray)),
+                Span::styled(entry.address.as_str(), Style::default().fg(Color::Cyan)),
+            ])));
+            for key in &entry.storage_keys {
+                details.push(ListItem::new(Line::from(vec![
+                    Span::raw("      "),
+                    Span::styled(key.as_str(), Style::default().fg(Color::DarkGray)),
+                ])));
+            }
+        }
+    }
+    details.push(ListItem::new(Line::from("")));
+}
 
-    // Add scroll indicator to title if content is scrollable
-    let title = if total_lines > visible_height {
-        format!(" Transaction Details (Line {}/{}) ",
-                adjusted_scroll + 1,
-                total_lines - adjusted_scroll.min(visible_height))
-    } else {
-        " Transaction Details ".to_string()
+/// Chain ID the sender signed for, and the v/r/s values
+fn push_signature_section<'a>(details: &mut Vec<ListItem<'a>>, tx: &'a Transaction) {
+    let chain_id = match tx.chain_id {
+        Some(id) => id.to_string(),
+        None => "none (pre-EIP-155)".to_string(),
     };
+    details.push(ListItem::new(Line::from(vec![
+        Span::styled("Chain ID: ", Style::default().fg(Color::Yellow).bold()),
+        Span::raw(chain_id),
+    ])));
 
-    // Get visible items based on scroll offset
-    let visible_items: Vec<ListItem> = details
-        .into_iter()
-        .skip(adjusted_scroll)
-        .take(visible_height)
-        .collect();
+    if let Some(signature) = &tx.signature {
+        let mut v = vec![
+            Span::styled("v: ", Style::default().fg(Color::Yellow).bold()),
+            Span::raw(signature.v.to_string()),
+        ];
+        if let Some(odd) = signature.y_parity {
+            v.push(Span::styled(
+                format!(" (y parity {})", u8::from(odd)),
+                Style::default().fg(Color::DarkGray),
+            ));
+        }
+        details.push(ListItem::new(Line::from(v)));
+        details.push(ListItem::new(Line::from(vec![
+            Span::styled("r: ", Style::default().fg(Color::Yellow).bold()),
+            Span::raw(signature.r.as_str()),
+        ])));
+        details.push(ListItem::new(Line::from(vec![
+            Span::styled("s: ", Style::default().fg(Color::Yellow).bold()),
+            Span::raw(signature.s.as_str()),
+        ])));
+    }
+    details.push(ListItem::new(Line::from("")));
+}
 
-    let list = List::new(visible_items)
-        .block(
-            Block::default()
-                .title(title)
-                .borders(Borders::ALL)
-                .border_style(Style::default().fg(Color::Cyan))
-                .border_type(ratatui::widgets::BorderType::Rounded),
-        )
-        .style(Style::default().bg(Color::Black));
+/// Display label of a race endpoint by index
+fn race_endpoint_name(state: &AppState, index: usize) -> String {
+    state.stats.race_endpoints
+        .get(index)
+        .map_or_else(|| format!("Endpoint {}", index + 1), |endpoint| endpoint.name.clone())
+}
 
-    frame.render_widget(list, area);
+/// Color of a lifecycle stage, shared with the list's Status column
+pub fn lifecycle_color(lifecycle: Lifecycle) -> Color {
+    match lifecycle {
+        Lifecycle::Pending => Color::Yellow,
+        Lifecycle::Mined => Color::Green,
+        Lifecycle::Finalized => Color::Cyan,
+        Lifecycle::Failed => Color::Red,
+        Lifecycle::Replaced => Color::Magenta,
+        Lifecycle::Dropped => Color::DarkGray,
+    }
+}
+
+/// Color of a transaction type, shared with the list's Type column
+pub fn type_color(tx_type: u8) -> Color {
+    match tx_type {
+        0 => Color::DarkGray,
+        1 => Color::Cyan,
+        2 => Color::Green,
+        3 => Color::Magenta,
+        _ => Color::Yellow,
+    }
 }
 
 /// Helper function to create a centered rect
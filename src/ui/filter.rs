@@ -1,4 +1,4 @@
-use crate::filter::FilterState;
+use crate::filter::{FilterMode, FilterState};
 use ratatui::prelude::*;
 use ratatui::widgets::{Block, Borders, Clear, Paragraph};
 
@@ -9,7 +9,7 @@ pub fn render_filter_input(frame: &mut Frame, filter: &FilterState) {
     }
 
     // Create a centered area for the filter input
-    let area = centered_rect(60, 20, frame.area());
+    let area = centered_rect(60, 30, frame.area());
 
     // Clear the background
     frame.render_widget(Clear, area);
@@ -19,41 +19,84 @@ pub fn render_filter_input(frame: &mut Frame, filter: &FilterState) {
 
     // Detect if it looks like a transaction hash
     let is_tx_hash = filter.is_transaction_hash();
+    let regex_mode = filter.mode() == FilterMode::Regex;
     let title_text = if is_tx_hash {
         "Transaction Hash Detected"
+    } else if regex_mode {
+        "Regex Filter"
     } else {
-        "Filter by Address or Transaction Hash"
+        "Filter Query"
+    };
+
+    let help_lines = if regex_mode {
+        vec![
+            Line::from(vec![
+                Span::raw("Case-insensitive regex, e.g. "),
+                Span::styled("^0x0000 or 7a250d5630b4cf539739df2c5dacb4c659f2488d", Style::default().fg(Color::DarkGray)),
+            ]),
+            Line::from(vec![
+                Span::styled(
+                    "Matched against hash, from, to, function name and calldata",
+                    Style::default().fg(Color::DarkGray),
+                ),
+            ]),
+        ]
+    } else {
+        vec![
+            Line::from(vec![
+                Span::raw("Address, transaction hash to fetch, or query, e.g. "),
+                Span::styled("from:0xabc fn:swap* value>1.5 && !status:failed", Style::default().fg(Color::DarkGray)),
+            ]),
+            Line::from(vec![
+                Span::styled(
+                    "Fields: from to hash fn selector arg arg.<name> has is value gas gasused gasprice nonce block chain type status | && || ! ( )",
+                    Style::default().fg(Color::DarkGray),
+                ),
+            ]),
+        ]
+    };
+
+    // Point at the parse error under the input, or hint at a hash fetch
+    let status_line = if let Some(error) = filter.parse_error() {
+        Line::from(vec![
+            Span::raw(" ".repeat(error.position)),
+            Span::styled(format!("^ {}", error.message), Style::default().fg(Color::Red)),
+        ])
+    } else if is_tx_hash {
+        Line::from(vec![
+            Span::styled("⚡ ", Style::default().fg(Color::Yellow)),
+            Span::raw("Transaction hash detected - will fetch on Enter"),
+        ])
+    } else {
+        Line::from("")
     };
 
     // Create the filter input widget
-    let input_widget = Paragraph::new(vec![
+    let mut lines = vec![
         Line::from(vec![
             Span::styled(title_text, Style::default().fg(Color::Cyan).bold()),
         ]),
         Line::from(""),
-        Line::from(vec![
-            Span::raw("Enter address to filter or transaction hash to fetch:"),
-        ]),
+    ];
+    lines.extend(help_lines);
+    lines.extend([
         Line::from(""),
         Line::from(input_text),
+        status_line,
         Line::from(""),
-        if is_tx_hash {
-            Line::from(vec![
-                Span::styled("⚡ ", Style::default().fg(Color::Yellow)),
-                Span::raw("Transaction hash detected - will fetch on Enter"),
-            ])
-        } else {
-            Line::from("")
-        },
         Line::from(vec![
             Span::styled("Enter", Style::default().fg(Color::Green)),
             Span::raw(if is_tx_hash { ": Fetch TX | " } else { ": Apply Filter | " }),
             Span::styled("Esc", Style::default().fg(Color::Red)),
             Span::raw(": Clear & Cancel | "),
             Span::styled("←→", Style::default().fg(Color::Yellow)),
-            Span::raw(": Move cursor"),
+            Span::raw(": Move cursor | "),
+            Span::styled("Ctrl+R", Style::default().fg(Color::Magenta)),
+            Span::raw(if regex_mode { ": Query mode" } else { ": Regex mode" }),
         ]),
-    ])
+    ]);
+
+    let input_widget = Paragraph::new(lines)
     .block(
         Block::default()
             .title(" Filter Input ")
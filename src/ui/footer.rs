@@ -35,10 +35,24 @@ pub fn render_footer(frame: &mut Frame, area: Rect, state: &AppState) {
             if state.show_new_on_top { "[New↑]" } else { "[New↓]" },
             Style::default().fg(Color::Yellow),
         ),
+        Span::raw(" | u: Unit "),
+        Span::styled(
+            format!("[{}]", state.value_unit.label(&stats.chain().symbol)),
+            Style::default().fg(Color::Yellow),
+        ),
+        Span::raw(" | p: "),
+        Span::styled(
+            if state.amount_format.full_precision {
+                "[Full]".to_string()
+            } else {
+                format!("[{} sig]", state.amount_format.significant_digits)
+            },
+            Style::default().fg(Color::Yellow),
+        ),
     ]);
 
     // Third line: connection status
-    let line3 = if !stats.connected {
+    let mut line3 = if !stats.connected {
         if let Some(error) = &stats.last_error {
             // Show error message (including "Connecting..." and "Fetching..." status)
             let color = if error.contains("Connecting") || error.contains("Fetching") {
@@ -68,12 +82,46 @@ pub fn render_footer(frame: &mut Frame, area: Rect, state: &AppState) {
         vec![
             Span::styled("Status: ", Style::default().fg(Color::Cyan).bold()),
             Span::styled(
-                format!("Connected to {}", truncate_url(&config.rpc_url)),
+                format!(
+                    "Connected to {}",
+                    truncate_url(stats.active_endpoint.as_ref().map_or(&config.rpc_url, |e| &e.url))
+                ),
                 Style::default().fg(Color::Green),
             ),
         ]
     };
 
+    // Append fetch pipeline backlog once hashes start flowing
+    let fetch = &stats.fetch;
+    if fetch.queued + fetch.in_flight + fetch.dropped + fetch.duplicates + fetch.pushed > 0 {
+        let color = if fetch.dropped > 0 { Color::Yellow } else { Color::DarkGray };
+        let mut summary = format!(
+            "Fetch: {} queued, {} in-flight, {} dropped, {} dup",
+            fetch.queued, fetch.in_flight, fetch.dropped, fetch.duplicates
+        );
+        if fetch.pushed > 0 {
+            summary.push_str(&format!(", {} pushed", fetch.pushed));
+        }
+        line3.push(Span::raw(" | "));
+        line3.push(Span::styled(summary, Style::default().fg(color)));
+    }
+
+    // Append a summary of user-supplied ABIs and signatures, if any were loaded
+    if let Some(report) = &stats.abi_report {
+        let mut summary = format!(
+            "ABIs: {} files ({} fn, {} events, {} errors)",
+            report.files, report.functions, report.events, report.errors
+        );
+        if report.contracts > 0 {
+            summary.push_str(&format!(" | Contracts: {}", report.contracts));
+        }
+        if report.db_signatures > 0 {
+            summary.push_str(&format!(" | Sig DB: {}", report.db_signatures));
+        }
+        line3.push(Span::raw(" | "));
+        line3.push(Span::styled(summary, Style::default().fg(Color::DarkGray)));
+    }
+
     let footer_text = vec![
         Line::from(line1),
         Line::from(line2),
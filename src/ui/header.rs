@@ -1,25 +1,51 @@
-use crate::app::{Config, Stats};
+use crate::app::{Config, ConnectionState, Stats};
+use crate::rpc::endpoints::format_rpc_url;
+use crate::rpc::SourceMode;
 use ratatui::prelude::*;
 use ratatui::widgets::{Block, Borders, Paragraph};
 
 pub fn render_header(frame: &mut Frame, area: Rect, stats: &Stats, config: &Config) {
     let runtime = format_runtime(stats.start_time);
-    let connection_status = if stats.connected {
-        ("✓", Color::Green)
-    } else {
-        ("✗", Color::Red)
+    let connection_status = format_connection(&stats.connection, stats.connected);
+
+    // Name of the endpoint in use, with its position when fallbacks are configured
+    let rpc_display = match &stats.active_endpoint {
+        _ if config.race_mode => {
+            let connected = stats.race_endpoints.iter().filter(|e| e.connected).count();
+            format!("Race: {}/{} endpoints", connected, stats.race_endpoints.len())
+        }
+        Some(endpoint) if endpoint.total > 1 => {
+            format!("{} ({}/{})", endpoint.name, endpoint.index + 1, endpoint.total)
+        }
+        Some(endpoint) => endpoint.name.clone(),
+        None => format_rpc_url(&config.rpc_url),
+    };
+
+    // Source mode, with the chain head when streaming mined blocks
+    let source_display = match (config.source_mode, stats.latest_block) {
+        (SourceMode::Blocks, Some(block)) => format!("{} #{}", config.source_mode.label(), block),
+        (mode, _) => mode.label().to_string(),
     };
 
-    // Format the RPC URL to show only the domain/important part
-    let rpc_display = format_rpc_url(&config.rpc_url);
+    // Chain reported by the endpoint, with its typical block time
+    let chain_display = match &stats.chain {
+        Some(chain) => match chain.block_time_ms {
+            Some(ms) => format!("{} ({}) ~{}s blocks", chain.name, chain.id, format_block_time(ms)),
+            None => format!("{} ({})", chain.name, chain.id),
+        },
+        None => "Chain: …".to_string(),
+    };
 
     let header_text = vec![
         Line::from(vec![
             Span::styled("Web3TxStream", Style::default().fg(Color::Cyan).bold()),
             Span::raw(" | "),
+            Span::styled(chain_display, Style::default().fg(Color::Blue)),
+            Span::raw(" | "),
             Span::styled(rpc_display, Style::default().fg(Color::Yellow)),
             Span::raw(" | "),
-            Span::raw("Connected: "),
+            Span::styled(source_display, Style::default().fg(Color::Magenta)),
+            Span::raw(" | "),
             Span::styled(connection_status.0, Style::default().fg(connection_status.1)),
             Span::raw(" | "),
             Span::raw(format!("TX: {} | ", format_number(stats.total_transactions))),
@@ -41,6 +67,42 @@ pub fn render_header(frame: &mut Frame, area: Rect, stats: &Stats, config: &Conf
     frame.render_widget(header_widget, area);
 }
 
+fn format_connection(connection: &ConnectionState, connected: bool) -> (String, Color) {
+    match connection {
+        ConnectionState::Connected if connected => ("Connected: ✓".to_string(), Color::Green),
+        ConnectionState::Connected => ("Connected: ✗".to_string(), Color::Red),
+        ConnectionState::Connecting => ("Connecting…".to_string(), Color::Yellow),
+        ConnectionState::Reconnecting { attempt, max_attempts, retry_at } => {
+            let remaining = retry_at.saturating_duration_since(std::time::Instant::now());
+            let attempts = if *max_attempts == 0 {
+                attempt.to_string()
+            } else {
+                format!("{}/{}", attempt, max_attempts)
+            };
+            (
+                format!("Reconnecting {} in {}s", attempts, remaining.as_secs_f32().ceil() as u64),
+                Color::Yellow,
+            )
+        }
+        ConnectionState::Stalled { since } => (
+            format!("Stalled: no data for {}s", since.elapsed().as_secs()),
+            Color::Yellow,
+        ),
+        ConnectionState::GaveUp { attempts } => (
+            format!("Gave up after {} attempts (r: retry)", attempts),
+            Color::Red,
+        ),
+    }
+}
+
+fn format_block_time(ms: u64) -> String {
+    if ms.is_multiple_of(1000) {
+        (ms / 1000).to_string()
+    } else {
+        format!("{:.2}", ms as f64 / 1000.0)
+    }
+}
+
 fn format_runtime(start_time: i64) -> String {
     let now = chrono::Utc::now().timestamp();
     let elapsed = now - start_time;
@@ -67,55 +129,3 @@ fn format_number(n: u64) -> String {
         n.to_string()
     }
 }
-
-fn format_rpc_url(url: &str) -> String {
-    // Extract the meaningful part of the URL
-    // Remove protocol (ws://, wss://, http://, https://)
-    let without_protocol = url
-        .strip_prefix("wss://")
-        .or_else(|| url.strip_prefix("ws://"))
-        .or_else(|| url.strip_prefix("https://"))
-        .or_else(|| url.strip_prefix("http://"))
-        .unwrap_or(url);
-
-    // If it's a known provider, simplify it
-    if without_protocol.contains("base-rpc.publicnode.com") {
-        "Base PublicNode".to_string()
-    } else if without_protocol.contains("base-mainnet") {
-        "Base Mainnet".to_string()
-    } else if without_protocol.contains("base-sepolia") || without_protocol.contains("base-testnet") {
-        "Base Sepolia".to_string()
-    } else if without_protocol.contains("mainnet.infura.io") {
-        "Ethereum Mainnet (Infura)".to_string()
-    } else if without_protocol.contains("polygon-rpc.com") {
-        "Polygon".to_string()
-    } else if without_protocol.contains("arb1.arbitrum.io") {
-        "Arbitrum One".to_string()
-    } else if without_protocol.contains("optimism.io") {
-        "Optimism".to_string()
-    } else if without_protocol.contains("localhost") || without_protocol.contains("127.0.0.1") {
-        "Local Node".to_string()
-    } else {
-        // For other URLs, just show the domain
-        without_protocol
-            .split('/')
-            .next()
-            .unwrap_or(without_protocol)
-            .to_string()
-    }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_format_rpc_url() {
-        assert_eq!(format_rpc_url("wss://base-rpc.publicnode.com"), "Base PublicNode");
-        assert_eq!(format_rpc_url("https://base-mainnet.g.alchemy.com/v2/abc"), "Base Mainnet");
-        assert_eq!(format_rpc_url("ws://localhost:8545"), "Local Node");
-        assert_eq!(format_rpc_url("wss://custom.provider.com/rpc"), "custom.provider.com");
-        assert_eq!(format_rpc_url("http://127.0.0.1:8545"), "Local Node");
-        assert_eq!(format_rpc_url("wss://mainnet.infura.io/ws/v3/key"), "Ethereum Mainnet (Infura)");
-    }
-}
\ No newline at end of file
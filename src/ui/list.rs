@@ -1,4 +1,5 @@
 use crate::app::AppState;
+use crate::filter::FilterMode;
 use ratatui::prelude::*;
 use ratatui::widgets::{Block, Borders, Cell, Row, Table};
 
@@ -13,7 +14,8 @@ pub fn render_transaction_list(
     let show_data_column = filtered_transactions.iter().any(|tx| tx.has_data());
 
     // Define table headers dynamically
-    let mut header_cells = vec!["Time", "Hash", "From", "To", "Value (ETH)", "Function"];
+    let value_header = format!("Value ({})", state.value_unit.label(&state.stats.chain().symbol));
+    let mut header_cells = vec!["Time", "Status", "Hash", "From", "To", "Type", value_header.as_str(), "Function"];
     if show_data_column {
         header_cells.push("Data");
     }
@@ -52,13 +54,42 @@ pub fn render_transaction_list(
                 Style::default()
             };
 
+            let lifecycle = tx.lifecycle(state.stats.finalized_block);
+            let lifecycle_style = if is_selected {
+                Style::default()
+            } else {
+                Style::default().fg(super::details::lifecycle_color(lifecycle))
+            };
+
+            let type_style = if is_selected {
+                Style::default()
+            } else {
+                Style::default().fg(super::details::type_color(tx.tx_type))
+            };
+
+            // Known contracts show their label instead of the address
+            let to_display = match &tx.to_label {
+                Some(label) => label.clone(),
+                None => tx.short_to().into_owned(),
+            };
+
+            // Selector-only matches are marked as guessed
+            let function_cell = if tx.is_function_guessed() {
+                Cell::from(format!("{}?", tx.function_name()))
+                    .style(Style::default().fg(function_color).italic())
+            } else {
+                Cell::from(tx.function_name()).style(Style::default().fg(function_color))
+            };
+
             let mut cells = vec![
                 Cell::from(tx.formatted_time()),
+                Cell::from(lifecycle.label()).style(lifecycle_style),
                 Cell::from(tx.short_hash().into_owned()),
                 Cell::from(tx.short_from().into_owned()),
-                Cell::from(tx.short_to().into_owned()).style(to_style),
-                Cell::from(tx.value.as_str()),
-                Cell::from(tx.function_name()).style(Style::default().fg(function_color)),
+                Cell::from(to_display).style(to_style),
+                Cell::from(tx.type_name()).style(type_style),
+                Cell::from(state.format_amount(tx.value, state.value_unit)),
+                function_cell,
             ];
 
             if show_data_column {
@@ -77,9 +108,11 @@ pub fn render_transaction_list(
     // Define column widths dynamically - use better allocation
     let mut widths = vec![
         Constraint::Length(8),   // Time (HH:MM:SS)
+        Constraint::Length(8),   // Status (pending, replaced, ...)
         Constraint::Length(15),  // Hash (0x123...abc)
         Constraint::Length(15),  // From (0x123...abc)
         Constraint::Length(20),  // To (0x123...abc or "Contract Creation")
+        Constraint::Length(6),   // Type (Legacy, 1559, ...)
         Constraint::Min(10),     // Value (flexible for different ETH amounts)
         Constraint::Min(15),     // Function (flexible for function names)
     ];
@@ -91,12 +124,14 @@ pub fn render_transaction_list(
     // Create title with filter indicator
     let title = if state.filter.has_query() {
         format!(
-            " Transactions [{}/{}] (Filtered: {}/{}) [Filter: {}] ",
+            " Transactions [{}/{}] (Filtered: {}/{}) [{}: {}{}] ",
             if filtered_transactions.is_empty() { 0 } else { scroll_state.selected + 1 },
             filtered_transactions.len(),
             filtered_transactions.len(),
             state.transactions.len(),
-            state.filter.query()
+            if state.filter.mode() == FilterMode::Regex { "Regex" } else { "Filter" },
+            state.filter.query(),
+            if state.filter.parse_error().is_some() { " (invalid)" } else { "" }
         )
     } else {
         format!(
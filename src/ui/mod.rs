@@ -4,18 +4,23 @@ pub mod footer;
 pub mod header;
 pub mod list;
 pub mod quit;
+pub mod race;
 
 use crate::app::AppState;
 use ratatui::prelude::*;
 use ratatui::Frame;
 
 pub fn render_ui(frame: &mut Frame, state: &AppState) {
+    // Race mode adds an endpoint summary panel above the footer
+    let race_height = if state.config.race_mode { race::panel_height(state) } else { 0 };
+
     // Create main layout
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
             Constraint::Length(3),  // Header
             Constraint::Min(10),    // Transaction list
+            Constraint::Length(race_height), // Race summary
             Constraint::Length(4),  // Footer (3 lines + border for status)
         ])
         .split(frame.area());
@@ -23,12 +28,15 @@ pub fn render_ui(frame: &mut Frame, state: &AppState) {
     // Render components
     header::render_header(frame, chunks[0], &state.stats, &state.config);
     list::render_transaction_list(frame, chunks[1], state);
-    footer::render_footer(frame, chunks[2], state);
+    if state.config.race_mode {
+        race::render_race_panel(frame, chunks[2], state);
+    }
+    footer::render_footer(frame, chunks[3], state);
 
     // Render transaction details popup if active
     if state.show_details {
         if let Some(ref tx) = state.selected_transaction {
-            details::render_transaction_details(frame, tx, state.details_scroll_offset);
+            details::render_transaction_details(frame, tx, state, state.details_scroll_offset);
         }
     }
 
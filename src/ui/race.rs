@@ -0,0 +1,60 @@
+use crate::app::race::summarize;
+use crate::app::AppState;
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, Cell, Row, Table};
+
+/// Height of the race panel for the configured endpoints (rows + header + borders)
+pub fn panel_height(state: &AppState) -> u16 {
+    state.stats.race_endpoints.len() as u16 + 3
+}
+
+/// Per-endpoint propagation summary over the buffered transactions
+pub fn render_race_panel(frame: &mut Frame, area: Rect, state: &AppState) {
+    let summaries = summarize(&state.stats.race_endpoints, &state.transactions);
+
+    let header = Row::new(vec!["Endpoint", "Status", "Seen", "First", "First %", "Avg lag"])
+        .style(Style::default().fg(Color::Yellow).bold());
+
+    let rows: Vec<Row> = summaries
+        .iter()
+        .map(|summary| {
+            let status = if summary.connected {
+                Cell::from("✓").style(Style::default().fg(Color::Green))
+            } else {
+                Cell::from("✗").style(Style::default().fg(Color::Red))
+            };
+            let lag = summary
+                .avg_lag_ms
+                .map_or_else(|| "-".to_string(), |lag| format!("+{:.0} ms", lag));
+
+            Row::new(vec![
+                Cell::from(summary.name.clone()).style(Style::default().fg(Color::Cyan)),
+                status,
+                Cell::from(summary.seen.to_string()),
+                Cell::from(summary.first.to_string()),
+                Cell::from(format!("{:.1}%", summary.first_pct)),
+                Cell::from(lag),
+            ])
+        })
+        .collect();
+
+    let widths = [
+        Constraint::Min(20),
+        Constraint::Length(6),
+        Constraint::Length(8),
+        Constraint::Length(8),
+        Constraint::Length(8),
+        Constraint::Length(10),
+    ];
+
+    let table = Table::new(rows, widths)
+        .header(header)
+        .block(
+            Block::default()
+                .title(" Race ")
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::DarkGray)),
+        );
+
+    frame.render_widget(table, area);
+}